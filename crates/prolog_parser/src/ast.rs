@@ -283,12 +283,19 @@ pub type OpDir = IndexMap<OpDirKey, OpDirValue>;
 #[derive(Debug, Clone, Copy)]
 pub struct MachineFlags {
     pub double_quotes: DoubleQuotes,
+    pub unknown: Unknown,
+    // how deeply the reader lets parentheses, brackets and braces nest
+    // in a single term. deeper terms could exhaust the stack of the
+    // code walking them.
+    pub max_term_depth: usize,
 }
 
 impl Default for MachineFlags {
     fn default() -> Self {
         MachineFlags {
             double_quotes: DoubleQuotes::default(),
+            unknown: Unknown::default(),
+            max_term_depth: 10_000,
         }
     }
 }
@@ -320,6 +327,20 @@ impl Default for DoubleQuotes {
     }
 }
 
+// what a call to an undefined procedure does.
+#[derive(Debug, Clone, Copy)]
+pub enum Unknown {
+    Error,
+    Fail,
+    Warn,
+}
+
+impl Default for Unknown {
+    fn default() -> Self {
+        Unknown::Error
+    }
+}
+
 pub fn default_op_dir() -> OpDir {
     let mut op_dir = OpDir::new();
 
@@ -342,12 +363,15 @@ pub enum ParserError {
     BackQuotedString(usize, usize),
     UnexpectedChar(char, usize, usize),
     UnexpectedEOF,
+    UnexpectedEOFInTerm(usize, usize),
     IO(IOError),
     IncompleteReduction(usize, usize),
     InvalidSingleQuotedCharacter(char),
     MissingQuote(usize, usize),
     NonPrologChar(usize, usize),
     ParseBigInt(usize, usize),
+    PriorityClash(usize, usize),
+    ResourceError(usize, usize),
     Utf8Error(usize, usize),
 }
 
@@ -356,10 +380,13 @@ impl ParserError {
         match self {
             &ParserError::BackQuotedString(line_num, col_num)
             | &ParserError::UnexpectedChar(_, line_num, col_num)
+            | &ParserError::UnexpectedEOFInTerm(line_num, col_num)
             | &ParserError::IncompleteReduction(line_num, col_num)
             | &ParserError::MissingQuote(line_num, col_num)
             | &ParserError::NonPrologChar(line_num, col_num)
             | &ParserError::ParseBigInt(line_num, col_num)
+            | &ParserError::PriorityClash(line_num, col_num)
+            | &ParserError::ResourceError(line_num, col_num)
             | &ParserError::Utf8Error(line_num, col_num) => Some((line_num, col_num)),
             _ => None,
         }
@@ -369,13 +396,17 @@ impl ParserError {
         match self {
             ParserError::BackQuotedString(..) => "back_quoted_string",
             ParserError::UnexpectedChar(..) => "unexpected_char",
-            ParserError::UnexpectedEOF => "unexpected_end_of_file",
+            ParserError::UnexpectedEOF | ParserError::UnexpectedEOFInTerm(..) => {
+                "unexpected_end_of_file"
+            }
             ParserError::IncompleteReduction(..) => "incomplete_reduction",
             ParserError::InvalidSingleQuotedCharacter(..) => "invalid_single_quoted_character",
             ParserError::IO(_) => "input_output_error",
             ParserError::MissingQuote(..) => "missing_quote",
             ParserError::NonPrologChar(..) => "non_prolog_character",
             ParserError::ParseBigInt(..) => "cannot_parse_big_int",
+            ParserError::PriorityClash(..) => "operator_priority_clash",
+            ParserError::ResourceError(..) => "term_depth",
             ParserError::Utf8Error(..) => "utf8_conversion_error",
         }
     }
@@ -66,6 +66,7 @@ pub struct Lexer<'a, R: Read> {
     pub(crate) flags: MachineFlags,
     pub(crate) line_num: usize,
     pub(crate) col_num: usize,
+    pub(crate) token_start: (usize, usize),
 }
 
 impl<'a, R: Read + fmt::Debug> fmt::Debug for Lexer<'a, R> {
@@ -75,6 +76,7 @@ impl<'a, R: Read + fmt::Debug> fmt::Debug for Lexer<'a, R> {
             .field("reader", &"&'a mut ParsingStream<R>") // Hacky solution.
             .field("line_num", &self.line_num)
             .field("col_num", &self.col_num)
+            .field("token_start", &self.token_start)
             .finish()
     }
 }
@@ -91,13 +93,18 @@ impl<'a, R: Read> Lexer<'a, R> {
             reader: src,
             line_num: 0,
             col_num: 0,
+            token_start: (0, 0),
         }
     }
 
+    // returning a character also takes back its column, which token
+    // spans rely on.
     fn return_char(&mut self, c: char) {
         if new_line_char!(c) {
             self.line_num -= 1;
             self.col_num = 0;
+        } else if self.col_num > 0 {
+            self.col_num -= 1;
         }
 
         self.reader.put_back(Ok(c));
@@ -800,6 +807,8 @@ impl<'a, R: Read> Lexer<'a, R> {
         let layout_inserted = self.scan_for_layout()?;
         let cr = self.lookahead_char();
 
+        self.token_start = (self.line_num, self.col_num);
+
         match cr {
             Ok(c) => {
                 if capital_letter_char!(c) || variable_indicator_char!(c) {
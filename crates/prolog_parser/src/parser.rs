@@ -48,6 +48,63 @@ struct TokenDesc {
     tt: TokenType,
     priority: usize,
     spec: u32,
+    start: (usize, usize),
+}
+
+/// The lines and columns at which a token or term begins and just
+/// past where it ends, both counted from zero.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Span {
+    pub start: (usize, usize),
+    pub end: (usize, usize),
+}
+
+/// The source layout of a term read by `Parser::read_term_with_layout`.
+/// `args` holds the layouts of the arguments of a compound term read
+/// in functional, operator, list or curly bracket notation, and is
+/// empty for everything else.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TermLayout {
+    pub span: Span,
+    pub args: Vec<TermLayout>,
+}
+
+impl From<Span> for TermLayout {
+    #[inline]
+    fn from(span: Span) -> Self {
+        TermLayout { span, args: vec![] }
+    }
+}
+
+// the layouts of subterms are only kept if the layout of the term
+// being read was asked for. the spans are tracked either way.
+fn layout_args<I: IntoIterator<Item = TermLayout>>(keep_layout: bool, args: I) -> Vec<TermLayout> {
+    if keep_layout {
+        args.into_iter().collect()
+    } else {
+        vec![]
+    }
+}
+
+// splits the layout of a term unfolded into n terms by unfold_by_str.
+fn unfold_layout(mut layout: TermLayout, n: usize) -> Vec<TermLayout> {
+    let mut layouts = Vec::with_capacity(n);
+
+    while layouts.len() + 1 < n {
+        if layout.args.len() != 2 {
+            layouts.push(layout.clone());
+            continue;
+        }
+
+        let right = layout.args.pop().unwrap();
+        let left = layout.args.pop().unwrap();
+
+        layouts.push(left);
+        layout = right;
+    }
+
+    layouts.push(layout);
+    layouts
 }
 
 pub fn get_clause_spec(
@@ -190,26 +247,52 @@ pub struct OpDesc {
 #[derive(Debug)]
 pub struct Parser<'a, R: Read> {
     lexer: Lexer<'a, R>,
-    tokens: Vec<Token>,
+    tokens: Vec<(Token, Span)>,
     stack: Vec<TokenDesc>,
     terms: Vec<Term>,
+    layouts: Vec<TermLayout>,
+    keep_layout: bool,
+    span: Span,
 }
 
-fn read_tokens<R: Read>(lexer: &mut Lexer<R>) -> Result<Vec<Token>, ParserError> {
+fn read_tokens<R: Read>(lexer: &mut Lexer<R>) -> Result<Vec<(Token, Span)>, ParserError> {
     let mut tokens = vec![];
+    let mut depth = 0usize;
 
     loop {
         match lexer.next_token() {
             Ok(token) => {
                 let at_end = token.is_end();
-                tokens.push(token);
+                let span = Span {
+                    start: lexer.token_start,
+                    end: (lexer.line_num, lexer.col_num),
+                };
+
+                match token {
+                    Token::Open | Token::OpenCT | Token::OpenList | Token::OpenCurly => {
+                        depth += 1;
+                    }
+                    Token::Close | Token::CloseList | Token::CloseCurly => {
+                        depth = depth.saturating_sub(1);
+                    }
+                    _ => {}
+                }
+
+                tokens.push((token, span));
 
                 if at_end {
                     break;
                 }
+
+                if depth > lexer.flags.max_term_depth {
+                    return Err(ParserError::ResourceError(lexer.line_num, lexer.col_num));
+                }
             }
             Err(ParserError::UnexpectedEOF) if !tokens.is_empty() => {
-                return Err(ParserError::IncompleteReduction(lexer.line_num, lexer.col_num));
+                return Err(ParserError::UnexpectedEOFInTerm(
+                    lexer.line_num,
+                    lexer.col_num,
+                ));
             }
             Err(e) => {
                 return Err(e);
@@ -233,6 +316,9 @@ impl<'a, R: Read> Parser<'a, R> {
             tokens: vec![],
             stack: Vec::new(),
             terms: Vec::new(),
+            layouts: Vec::new(),
+            keep_layout: false,
+            span: Span::default(),
         }
     }
 
@@ -264,7 +350,10 @@ impl<'a, R: Read> Parser<'a, R> {
             )),
             TokenType::Comma => Some((clause_name!(","), Some(SharedOpDesc::new(1000, XFY)))),
             TokenType::Term => match self.terms.pop() {
-                Some(Term::Constant(_, Constant::Atom(atom, spec))) => Some((atom, spec)),
+                Some(Term::Constant(_, Constant::Atom(atom, spec))) => {
+                    self.layouts.pop();
+                    Some((atom, spec))
+                }
                 Some(term) => {
                     self.terms.push(term);
                     None
@@ -276,9 +365,9 @@ impl<'a, R: Read> Parser<'a, R> {
     }
 
     fn push_binary_op(&mut self, td: TokenDesc, spec: Specifier) {
-        if let Some(arg2) = self.terms.pop() {
+        if let (Some(arg2), Some(layout2)) = (self.terms.pop(), self.layouts.pop()) {
             if let Some((name, shared_op_desc)) = self.get_term_name(td) {
-                if let Some(arg1) = self.terms.pop() {
+                if let (Some(arg1), Some(layout1)) = (self.terms.pop(), self.layouts.pop()) {
                     let term = Term::Clause(
                         Cell::default(),
                         name,
@@ -286,11 +375,21 @@ impl<'a, R: Read> Parser<'a, R> {
                         shared_op_desc,
                     );
 
+                    let span = Span {
+                        start: layout1.span.start,
+                        end: layout2.span.end,
+                    };
+
                     self.terms.push(term);
+                    self.layouts.push(TermLayout {
+                        span,
+                        args: layout_args(self.keep_layout, [layout1, layout2]),
+                    });
                     self.stack.push(TokenDesc {
                         tt: TokenType::Term,
                         priority: td.priority,
                         spec,
+                        start: span.start,
                     });
                 }
             }
@@ -298,21 +397,34 @@ impl<'a, R: Read> Parser<'a, R> {
     }
 
     fn push_unary_op(&mut self, td: TokenDesc, spec: Specifier, assoc: u32) {
-        if let Some(mut arg1) = self.terms.pop() {
-            if let Some(mut name) = self.terms.pop() {
-                if is_postfix!(assoc) {
+        if let (Some(mut arg1), Some(arg1_layout)) = (self.terms.pop(), self.layouts.pop()) {
+            if let (Some(mut name), Some(name_layout)) = (self.terms.pop(), self.layouts.pop()) {
+                let span = Span {
+                    start: name_layout.span.start,
+                    end: arg1_layout.span.end,
+                };
+
+                let arg_layout = if is_postfix!(assoc) {
                     swap(&mut arg1, &mut name);
-                }
+                    name_layout
+                } else {
+                    arg1_layout
+                };
 
                 if let Term::Constant(_, Constant::Atom(name, shared_op_desc)) = name {
                     let term =
                         Term::Clause(Cell::default(), name, vec![Box::new(arg1)], shared_op_desc);
 
                     self.terms.push(term);
+                    self.layouts.push(TermLayout {
+                        span,
+                        args: layout_args(self.keep_layout, [arg_layout]),
+                    });
                     self.stack.push(TokenDesc {
                         tt: TokenType::Term,
                         priority: td.priority,
                         spec,
+                        start: span.start,
                     });
                 }
             }
@@ -330,10 +442,12 @@ impl<'a, R: Read> Parser<'a, R> {
 
         self.terms
             .push(Term::Constant(Cell::default(), Constant::Atom(atom, spec)));
+        self.layouts.push(TermLayout::from(self.span));
         self.stack.push(TokenDesc {
             tt: TokenType::Term,
             priority,
             spec: assoc,
+            start: self.span.start,
         });
     }
 
@@ -381,7 +495,16 @@ impl<'a, R: Read> Parser<'a, R> {
             Token::End => TokenType::End,
         };
 
-        self.stack.push(TokenDesc { tt, priority, spec });
+        if let TokenType::Term = tt {
+            self.layouts.push(TermLayout::from(self.span));
+        }
+
+        self.stack.push(TokenDesc {
+            tt,
+            priority,
+            spec,
+            start: self.span.start,
+        });
     }
 
     fn reduce_op(&mut self, priority: usize) {
@@ -489,8 +612,21 @@ impl<'a, R: Read> Parser<'a, R> {
                 self.stack.truncate(stack_len + 1);
 
                 let mut subterms: Vec<_> = self.terms.drain(idx..).map(Box::new).collect();
+                let arg_layouts = layout_args(self.keep_layout, self.layouts.drain(idx..));
+                let name_layout = self.layouts.pop();
+
+                if let (Some(name), Some(name_layout)) = (
+                    self.terms.pop().and_then(|t| self.atomize_term(&t)),
+                    name_layout,
+                ) {
+                    let layout = TermLayout {
+                        span: Span {
+                            start: name_layout.span.start,
+                            end: self.span.end,
+                        },
+                        args: arg_layouts,
+                    };
 
-                if let Some(name) = self.terms.pop().and_then(|t| self.atomize_term(&t)) {
                     // reduce the '.' functor to a cons cell if it applies.
                     if name.as_str() == "." && subterms.len() == 2 {
                         let tail = subterms.pop().unwrap();
@@ -503,10 +639,13 @@ impl<'a, R: Read> Parser<'a, R> {
                             .push(Term::Clause(Cell::default(), name, subterms, spec));
                     }
 
+                    self.layouts.push(layout);
+
                     if let Some(&mut TokenDesc {
                         ref mut priority,
                         ref mut spec,
                         ref mut tt,
+                        ..
                     }) = self.stack.last_mut()
                     {
                         *tt = TokenType::Term;
@@ -532,7 +671,7 @@ impl<'a, R: Read> Parser<'a, R> {
     }
 
     fn expand_comma_compacted_terms(&mut self, index: usize) -> usize {
-        if let Some(term) = self.terms.pop() {
+        if let (Some(term), Some(layout)) = (self.terms.pop(), self.layouts.pop()) {
             let op_desc = self.stack[index - 1];
 
             if 0 < op_desc.priority && op_desc.priority < self.stack[index].priority {
@@ -544,6 +683,7 @@ impl<'a, R: Read> Parser<'a, R> {
                         let terms = unfold_by_str(term, ",");
                         let arity = terms.len() - 1;
 
+                        self.layouts.extend(unfold_layout(layout, terms.len()));
                         self.terms.extend(terms.into_iter());
                         return arity;
                     }
@@ -552,6 +692,7 @@ impl<'a, R: Read> Parser<'a, R> {
             }
 
             self.terms.push(term);
+            self.layouts.push(layout);
         }
 
         0
@@ -601,6 +742,10 @@ impl<'a, R: Read> Parser<'a, R> {
 
                 self.terms
                     .push(Term::Constant(Cell::default(), Constant::EmptyList));
+                self.layouts.push(TermLayout::from(Span {
+                    start: td.start,
+                    end: self.span.end,
+                }));
                 return Ok(true);
             }
         }
@@ -615,12 +760,16 @@ impl<'a, R: Read> Parser<'a, R> {
         // we know that self.stack.len() >= 2 by this point.
         let idx = self.stack.len() - 2;
         let list_len = self.stack.len() - 2 * arity;
+        let list_start = self.stack[list_len].start;
 
-        let end_term = if self.stack[idx].tt != TokenType::HeadTailSeparator {
-            Term::Constant(Cell::default(), Constant::EmptyList)
+        let (end_term, end_layout) = if self.stack[idx].tt != TokenType::HeadTailSeparator {
+            (
+                Term::Constant(Cell::default(), Constant::EmptyList),
+                TermLayout::from(self.span),
+            )
         } else {
-            let term = match self.terms.pop() {
-                Some(term) => term,
+            let (term, layout) = match (self.terms.pop(), self.layouts.pop()) {
+                (Some(term), Some(layout)) => (term, layout),
                 _ => {
                     return Err(ParserError::IncompleteReduction(
                         self.lexer.line_num,
@@ -635,7 +784,7 @@ impl<'a, R: Read> Parser<'a, R> {
 
             arity -= 1;
 
-            term
+            (term, layout)
         };
 
         let idx = self.terms.len() - arity;
@@ -644,14 +793,33 @@ impl<'a, R: Read> Parser<'a, R> {
             Term::Cons(Cell::default(), Box::new(t), Box::new(acc))
         });
 
+        let end = self.span.end;
+        let keep_layout = self.keep_layout;
+
+        let mut list_layout = self
+            .layouts
+            .drain(idx..)
+            .rev()
+            .fold(end_layout, |acc, layout| TermLayout {
+                span: Span {
+                    start: layout.span.start,
+                    end,
+                },
+                args: layout_args(keep_layout, [layout, acc]),
+            });
+
+        list_layout.span.start = list_start;
+
         self.stack.truncate(list_len);
 
         self.stack.push(TokenDesc {
             tt: TokenType::Term,
             priority: 0,
             spec: TERM,
+            start: list_start,
         });
         self.terms.push(list);
+        self.layouts.push(list_layout);
 
         Ok(true)
     }
@@ -669,6 +837,10 @@ impl<'a, R: Read> Parser<'a, R> {
 
                 let term = Term::Constant(Cell::default(), atom!("{}", self.lexer.atom_tbl));
                 self.terms.push(term);
+                self.layouts.push(TermLayout::from(Span {
+                    start: td.start,
+                    end: self.span.end,
+                }));
                 return Ok(true);
             }
         }
@@ -687,8 +859,8 @@ impl<'a, R: Read> Parser<'a, R> {
                         oc.priority = 0;
                         oc.spec = TERM;
 
-                        let term = match self.terms.pop() {
-                            Some(term) => term,
+                        let (term, layout) = match (self.terms.pop(), self.layouts.pop()) {
+                            (Some(term), Some(layout)) => (term, layout),
                             _ => {
                                 return Err(ParserError::IncompleteReduction(
                                     self.lexer.line_num,
@@ -703,6 +875,13 @@ impl<'a, R: Read> Parser<'a, R> {
                             vec![Box::new(term)],
                             None,
                         ));
+                        self.layouts.push(TermLayout {
+                            span: Span {
+                                start: oc.start,
+                                end: self.span.end,
+                            },
+                            args: layout_args(self.keep_layout, [layout]),
+                        });
 
                         return Ok(true);
                     }
@@ -736,6 +915,10 @@ impl<'a, R: Read> Parser<'a, R> {
                 if let Some(atom) = sep_to_atom(self.stack[idx].tt) {
                     self.terms
                         .push(Term::Constant(Cell::default(), Constant::Atom(atom, None)));
+                    self.layouts.push(TermLayout::from(Span {
+                        start: td.start,
+                        end: self.span.end,
+                    }));
                 }
 
                 self.stack[idx].spec = TERM;
@@ -756,7 +939,12 @@ impl<'a, R: Read> Parser<'a, R> {
         }) = get_op_desc(name.clone(), op_dir)
         {
             if (pre > 0 && inf + post > 0) || is_negate!(spec) {
-                match self.tokens.last().ok_or(ParserError::UnexpectedEOF)? {
+                match self
+                    .tokens
+                    .last()
+                    .map(|(token, _)| token)
+                    .ok_or(ParserError::UnexpectedEOF)?
+                {
                     // do this when layout hasn't been inserted,
                     // ie. why we don't match on Token::Open.
                     Token::OpenCT => {
@@ -863,7 +1051,14 @@ impl<'a, R: Read> Parser<'a, R> {
                         self.stack.pop();
                         self.terms.pop();
 
+                        let start = self.layouts.pop().map(|layout| layout.span.start);
+
                         self.shift(Token::Constant(constr(negator(n))), 0, TERM);
+
+                        if let (Some(layout), Some(start)) = (self.layouts.last_mut(), start) {
+                            layout.span.start = start;
+                        }
+
                         return;
                     }
                     _ => {}
@@ -985,9 +1180,42 @@ impl<'a, R: Read> Parser<'a, R> {
 
     // on success, returns the parsed term and the number of lines read.
     pub fn read_term(&mut self, op_dir: &CompositeOpDir) -> Result<Term, ParserError> {
+        self.read_term_with_priority(op_dir, 1200)
+    }
+
+    /// Reads a term whose principal operator has a priority of at most
+    /// `max_priority`. An argument, for instance, is read at 999 so
+    /// that an unparenthesized `,` can't be its principal functor.
+    pub fn read_term_with_priority(
+        &mut self,
+        op_dir: &CompositeOpDir,
+        max_priority: usize,
+    ) -> Result<Term, ParserError> {
+        self.keep_layout = false;
+        self.read_term_impl(op_dir, max_priority)
+            .map(|(term, _)| term)
+    }
+
+    /// Reads a term along with its source layout. Building the layout
+    /// costs an allocation per compound subterm, so `read_term` skips
+    /// it.
+    pub fn read_term_with_layout(
+        &mut self,
+        op_dir: &CompositeOpDir,
+    ) -> Result<(Term, TermLayout), ParserError> {
+        self.keep_layout = true;
+        self.read_term_impl(op_dir, 1200)
+    }
+
+    fn read_term_impl(
+        &mut self,
+        op_dir: &CompositeOpDir,
+        max_priority: usize,
+    ) -> Result<(Term, TermLayout), ParserError> {
         self.tokens = read_tokens(&mut self.lexer)?;
 
-        while let Some(token) = self.tokens.pop() {
+        while let Some((token, span)) = self.tokens.pop() {
+            self.span = span;
             self.shift_token(token, op_dir)?;
         }
 
@@ -1000,10 +1228,19 @@ impl<'a, R: Read> Parser<'a, R> {
             ));
         }
 
-        match self.terms.pop() {
-            Some(term) => {
+        if let Some(desc) = self.stack.last() {
+            if desc.priority > max_priority {
+                return Err(ParserError::PriorityClash(
+                    self.lexer.line_num,
+                    self.lexer.col_num,
+                ));
+            }
+        }
+
+        match (self.terms.pop(), self.layouts.pop()) {
+            (Some(term), Some(layout)) => {
                 if self.terms.is_empty() {
-                    Ok(term)
+                    Ok((term, layout))
                 } else {
                     Err(ParserError::IncompleteReduction(
                         self.lexer.line_num,
@@ -1,3 +1,5 @@
+#[cfg(feature = "atom_stats")]
+use std::cell::Cell;
 use std::cell::{RefCell, RefMut};
 use std::cmp::Ordering;
 use std::collections::HashSet;
@@ -6,9 +8,20 @@ use std::hash::{Hash, Hasher};
 use std::ops::Deref;
 use std::rc::Rc;
 
+// counts the lookups of TabledRc::new that found their atom already
+// in the table (hits) and those that had to add it (misses).
+#[cfg(feature = "atom_stats")]
+#[derive(Debug, Default)]
+struct TableStats {
+    hits: Cell<usize>,
+    misses: Cell<usize>,
+}
+
 pub struct TabledData<T> {
     table: Rc<RefCell<HashSet<Rc<T>>>>,
     pub(crate) module_name: Rc<String>,
+    #[cfg(feature = "atom_stats")]
+    stats: Rc<TableStats>,
 }
 
 impl<T: Hash + Eq + fmt::Debug> fmt::Debug for TabledData<T> {
@@ -25,6 +38,8 @@ impl<T> Clone for TabledData<T> {
         TabledData {
             table: self.table.clone(),
             module_name: self.module_name.clone(),
+            #[cfg(feature = "atom_stats")]
+            stats: self.stats.clone(),
         }
     }
 }
@@ -41,6 +56,8 @@ impl<T: Hash + Eq> TabledData<T> {
         TabledData {
             table: Rc::new(RefCell::new(HashSet::new())),
             module_name,
+            #[cfg(feature = "atom_stats")]
+            stats: Rc::new(TableStats::default()),
         }
     }
 
@@ -48,6 +65,39 @@ impl<T: Hash + Eq> TabledData<T> {
     pub fn borrow_mut(&self) -> RefMut<HashSet<Rc<T>>> {
         self.table.borrow_mut()
     }
+
+    #[inline]
+    pub fn num_entries(&self) -> usize {
+        self.table.borrow().len()
+    }
+
+    // returns the (hits, misses) of the table. both are always 0 unless
+    // the atom_stats feature is enabled.
+    #[cfg(feature = "atom_stats")]
+    #[inline]
+    pub fn stats(&self) -> (usize, usize) {
+        (self.stats.hits.get(), self.stats.misses.get())
+    }
+
+    #[cfg(not(feature = "atom_stats"))]
+    #[inline]
+    pub fn stats(&self) -> (usize, usize) {
+        (0, 0)
+    }
+
+    #[inline(always)]
+    fn record_lookup(&self, _hit: bool) {
+        #[cfg(feature = "atom_stats")]
+        {
+            let counter = if _hit {
+                &self.stats.hits
+            } else {
+                &self.stats.misses
+            };
+
+            counter.set(counter.get() + 1);
+        }
+    }
 }
 
 pub struct TabledRc<T: Hash + Eq> {
@@ -104,8 +154,14 @@ impl<T: Hash + Eq> Hash for TabledRc<T> {
 impl<T: Hash + Eq + ToString> TabledRc<T> {
     pub fn new(atom: T, table: TabledData<T>) -> Self {
         let atom = match table.borrow_mut().take(&atom) {
-            Some(atom) => atom,
-            None => Rc::new(atom),
+            Some(atom) => {
+                table.record_lookup(true);
+                atom
+            }
+            None => {
+                table.record_lookup(false);
+                Rc::new(atom)
+            }
         };
 
         table.borrow_mut().insert(atom.clone());
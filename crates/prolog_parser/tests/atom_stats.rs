@@ -0,0 +1,18 @@
+#![cfg(feature = "atom_stats")]
+
+use prolog_parser::ast::*;
+use prolog_parser::tabled_rc::{TabledData, TabledRc};
+
+use std::rc::Rc;
+
+#[test]
+fn interning_counts_hits_and_misses() {
+    let atom_tbl: TabledData<Atom> = TabledData::new(Rc::new("my_module".to_string()));
+
+    let _first = TabledRc::new("foo".to_string(), atom_tbl.clone());
+    assert_eq!(atom_tbl.stats(), (0, 1));
+
+    let _second = TabledRc::new("foo".to_string(), atom_tbl.clone());
+    assert_eq!(atom_tbl.stats(), (1, 1));
+    assert_eq!(atom_tbl.num_entries(), 1);
+}
@@ -0,0 +1,60 @@
+use prolog_parser::ast::*;
+use prolog_parser::parser::Parser;
+use prolog_parser::tabled_rc::TabledData;
+
+use std::rc::Rc;
+
+fn read_term_with_priority(text: &str, max_priority: usize) -> Result<Term, ParserError> {
+    let atom_tbl = TabledData::new(Rc::new("my_module".to_string()));
+    let flags = MachineFlags::default();
+    let op_dir = default_op_dir();
+    let mut stream = parsing_stream(text.as_bytes())?;
+    let mut parser = Parser::new(&mut stream, atom_tbl, flags);
+
+    parser.read_term_with_priority(&CompositeOpDir::new(&op_dir, None), max_priority)
+}
+
+fn name_and_arity(term: &Term) -> Option<(String, usize)> {
+    term.name()
+        .map(|name| (name.as_str().to_string(), term.arity()))
+}
+
+#[test]
+fn comma_separates_arguments() -> Result<(), ParserError> {
+    let term = read_term_with_priority("f(a, b).", 999)?;
+    assert_eq!(name_and_arity(&term), Some(("f".to_string(), 2)));
+    Ok(())
+}
+
+#[test]
+fn parenthesized_comma_is_an_operator() -> Result<(), ParserError> {
+    let term = read_term_with_priority("f((a, b)).", 999)?;
+    assert_eq!(name_and_arity(&term), Some(("f".to_string(), 1)));
+
+    match term {
+        Term::Clause(_, _, ref args, _) => {
+            assert_eq!(name_and_arity(&args[0]), Some((",".to_string(), 2)));
+        }
+        _ => assert!(false),
+    }
+
+    Ok(())
+}
+
+#[test]
+fn unparenthesized_comma_exceeds_argument_priority() {
+    match read_term_with_priority("a, b.", 999) {
+        Err(ParserError::PriorityClash(..)) => (),
+        _ => assert!(false),
+    }
+}
+
+#[test]
+fn unparenthesized_comma_within_clause_priority() -> Result<(), ParserError> {
+    let term = read_term_with_priority("a, b.", 1200)?;
+    assert_eq!(name_and_arity(&term), Some((",".to_string(), 2)));
+
+    let term = read_term_with_priority("(a :- b).", 999)?;
+    assert_eq!(name_and_arity(&term), Some((":-".to_string(), 2)));
+    Ok(())
+}
@@ -0,0 +1,54 @@
+use prolog_parser::ast::*;
+use prolog_parser::parser::Parser;
+use prolog_parser::tabled_rc::TabledData;
+
+use std::rc::Rc;
+
+fn read_term(text: &str, max_term_depth: usize) -> Result<Term, ParserError> {
+    let atom_tbl = TabledData::new(Rc::new("my_module".to_string()));
+    let flags = MachineFlags {
+        max_term_depth,
+        ..MachineFlags::default()
+    };
+    let op_dir = default_op_dir();
+    let mut stream = parsing_stream(text.as_bytes())?;
+    let mut parser = Parser::new(&mut stream, atom_tbl, flags);
+
+    parser.read_term(&CompositeOpDir::new(&op_dir, None))
+}
+
+#[test]
+fn deep_nesting_is_a_resource_error() {
+    let text = format!("{}a{}.", "(".repeat(1_000_000), ")".repeat(1_000_000));
+
+    match read_term(&text, MachineFlags::default().max_term_depth) {
+        Err(ParserError::ResourceError(..)) => (),
+        _ => assert!(false),
+    }
+
+    let text = format!("{}a{}.", "f(".repeat(200), ")".repeat(200));
+
+    match read_term(&text, 100) {
+        Err(ParserError::ResourceError(..)) => (),
+        _ => assert!(false),
+    }
+}
+
+#[test]
+fn nesting_within_the_limit() -> Result<(), ParserError> {
+    let text = format!("{}a{}.", "[".repeat(100), "]".repeat(100));
+    read_term(&text, 100)?;
+    Ok(())
+}
+
+#[test]
+fn long_flat_lists_are_not_limited() -> Result<(), ParserError> {
+    let elems = vec!["a"; 1000].join(",");
+
+    match read_term(&format!("[{}].", elems), 100)? {
+        Term::Cons(..) => (),
+        _ => assert!(false),
+    }
+
+    Ok(())
+}
@@ -51,6 +51,9 @@ pub(crate) trait Allocator<'a> {
 
     fn advance_arg(&mut self);
 
+    // the highest numbered register allocated since the last reset.
+    fn max_reg_allocated(&self) -> usize;
+
     fn bindings(&self) -> &AllocVarDict;
     fn bindings_mut(&mut self) -> &mut AllocVarDict;
 
@@ -163,6 +163,7 @@ pub(crate) enum SystemClauseType {
     CopyTermWithoutAttrVars,
     CheckCutPoint,
     Close,
+    CloseOutputString,
     CopyToLiftedHeap,
     CreatePartialString,
     CurrentHostname,
@@ -210,15 +211,26 @@ pub(crate) enum SystemClauseType {
     HeadIsDynamic,
     InstallSCCCleaner,
     InstallInferenceCounter,
+    InitLiftedHeapGroundCache,
     LiftedHeapLength,
+    MSort,
+    NumList,
+    NumListStep,
+    StringCode,
+    StringChars,
+    StringCodes,
+    TermHash,
     LoadLibraryAsStream,
     ModuleExists,
+    IsImportedPredicate,
     NextEP,
     NoSuchPredicate,
     NumberToChars,
     NumberToCodes,
     OpDeclaration,
     Open,
+    OpenOutputString,
+    OpenInputString,
     NextStream,
     PartialStringTail,
     PeekByte,
@@ -229,8 +241,12 @@ pub(crate) enum SystemClauseType {
     PutChar,
     PutChars,
     PutCode,
+    Tab,
+    DowncaseAtom,
+    UpcaseAtom,
     REPL(REPLCodePtr),
     ReadQueryTerm,
+    ReadNumber,
     ReadTerm,
     RedoAttrVarBinding,
     RemoveCallPolicyCheck,
@@ -252,9 +268,16 @@ pub(crate) enum SystemClauseType {
     GetCurrentBlock,
     GetCutPoint,
     GetDoubleQuotes,
+    GetMaxInteger,
+    GetMaxTermDepth,
+    GetMinInteger,
+    GetUnknown,
     InstallNewBlock,
     Maybe,
     CpuNow,
+    MemoryStatistics,
+    AtomTableStatistics,
+    InferenceCount,
     CurrentTime,
     QuotedToken,
     ReadTermFromChars,
@@ -263,8 +286,12 @@ pub(crate) enum SystemClauseType {
     SetBall,
     SetCutPointByDefault(RegType),
     SetDoubleQuotes,
+    SetMaxTermDepth,
+    SetUnknown,
     SetSeed,
     SkipMaxList,
+    Succ,
+    Plus,
     Sleep,
     SocketClientOpen,
     SocketServerOpen,
@@ -415,6 +442,9 @@ impl SystemClauseType {
                 clause_name!("$cpp_discontiguous_property")
             }
             &SystemClauseType::REPL(REPLCodePtr::AbolishClause) => clause_name!("$abolish_clause"),
+            &SystemClauseType::REPL(REPLCodePtr::DeclareDynamicPredicate) => {
+                clause_name!("$declare_dynamic_predicate")
+            }
             &SystemClauseType::REPL(REPLCodePtr::IsConsistentWithTermQueue) => {
                 clause_name!("$is_consistent_with_term_queue")
             }
@@ -428,6 +458,7 @@ impl SystemClauseType {
                 clause_name!("$add_non_counted_backtracking")
             }
             &SystemClauseType::Close => clause_name!("$close"),
+            &SystemClauseType::CloseOutputString => clause_name!("$close_output_string"),
             &SystemClauseType::CopyToLiftedHeap => clause_name!("$copy_to_lh"),
             &SystemClauseType::DeleteAttribute => clause_name!("$del_attr_non_head"),
             &SystemClauseType::DeleteHeadAttribute => clause_name!("$del_attr_head"),
@@ -465,11 +496,17 @@ impl SystemClauseType {
             &SystemClauseType::LookupDBRef => clause_name!("$lookup_db_ref"),
             &SystemClauseType::LookupOpDBRef => clause_name!("$lookup_op_db_ref"),
             &SystemClauseType::GetDoubleQuotes => clause_name!("$get_double_quotes"),
+            &SystemClauseType::GetMaxInteger => clause_name!("$get_max_integer"),
+            &SystemClauseType::GetMaxTermDepth => clause_name!("$get_max_term_depth"),
+            &SystemClauseType::GetMinInteger => clause_name!("$get_min_integer"),
+            &SystemClauseType::GetUnknown => clause_name!("$get_unknown"),
             //          &SystemClauseType::GetModuleClause => clause_name!("$get_module_clause"),
             &SystemClauseType::GetSCCCleaner => clause_name!("$get_scc_cleaner"),
             &SystemClauseType::Halt => clause_name!("$halt"),
             &SystemClauseType::HeadIsDynamic => clause_name!("$head_is_dynamic"),
             &SystemClauseType::Open => clause_name!("$open"),
+            &SystemClauseType::OpenOutputString => clause_name!("$open_output_string"),
+            &SystemClauseType::OpenInputString => clause_name!("$open_input_string"),
             &SystemClauseType::OpDeclaration => clause_name!("$op"),
             &SystemClauseType::InstallSCCCleaner => clause_name!("$install_scc_cleaner"),
             &SystemClauseType::InstallInferenceCounter => {
@@ -480,9 +517,22 @@ impl SystemClauseType {
             &SystemClauseType::PeekByte => clause_name!("$peek_byte"),
             &SystemClauseType::PeekChar => clause_name!("$peek_char"),
             &SystemClauseType::PeekCode => clause_name!("$peek_code"),
+            &SystemClauseType::InitLiftedHeapGroundCache => {
+                clause_name!("$init_lh_ground_cache")
+            }
             &SystemClauseType::LiftedHeapLength => clause_name!("$lh_length"),
+            &SystemClauseType::MSort => clause_name!("$msort"),
+            &SystemClauseType::NumList => clause_name!("$numlist"),
+            &SystemClauseType::NumListStep => clause_name!("$numlist_step"),
+            &SystemClauseType::StringCode => clause_name!("$string_code"),
+            &SystemClauseType::StringChars => clause_name!("$string_chars"),
+            &SystemClauseType::StringCodes => clause_name!("$string_codes"),
+            &SystemClauseType::TermHash => clause_name!("$term_hash"),
             &SystemClauseType::Maybe => clause_name!("maybe"),
             &SystemClauseType::CpuNow => clause_name!("$cpu_now"),
+            &SystemClauseType::MemoryStatistics => clause_name!("$memory_statistics"),
+            &SystemClauseType::AtomTableStatistics => clause_name!("$atom_table_statistics"),
+            &SystemClauseType::InferenceCount => clause_name!("$inference_count"),
             &SystemClauseType::CurrentTime => clause_name!("$current_time"),
             // &SystemClauseType::ModuleAssertDynamicPredicateToFront => {
             //     clause_name!("$module_asserta")
@@ -492,6 +542,7 @@ impl SystemClauseType {
             // }
             //          &SystemClauseType::ModuleHeadIsDynamic => clause_name!("$module_head_is_dynamic"),
             &SystemClauseType::ModuleExists => clause_name!("$module_exists"),
+            &SystemClauseType::IsImportedPredicate => clause_name!("$is_imported_predicate"),
             &SystemClauseType::NextStream => clause_name!("$next_stream"),
             &SystemClauseType::NoSuchPredicate => clause_name!("$no_such_predicate"),
             &SystemClauseType::NumberToChars => clause_name!("$number_to_chars"),
@@ -511,6 +562,15 @@ impl SystemClauseType {
             &SystemClauseType::PutCode => {
                 clause_name!("$put_code")
             }
+            &SystemClauseType::Tab => {
+                clause_name!("$tab")
+            }
+            &SystemClauseType::DowncaseAtom => {
+                clause_name!("$downcase_atom")
+            }
+            &SystemClauseType::UpcaseAtom => {
+                clause_name!("$upcase_atom")
+            }
             &SystemClauseType::QuotedToken => {
                 clause_name!("$quoted_token")
             }
@@ -538,6 +598,7 @@ impl SystemClauseType {
             &SystemClauseType::InstallNewBlock => clause_name!("$install_new_block"),
             &SystemClauseType::NextEP => clause_name!("$nextEP"),
             &SystemClauseType::ReadQueryTerm => clause_name!("$read_query_term"),
+            &SystemClauseType::ReadNumber => clause_name!("$read_number"),
             &SystemClauseType::ReadTerm => clause_name!("$read_term"),
             &SystemClauseType::ReadTermFromChars => clause_name!("$read_term_from_chars"),
             &SystemClauseType::ResetBlock => clause_name!("$reset_block"),
@@ -546,7 +607,11 @@ impl SystemClauseType {
             &SystemClauseType::SetBall => clause_name!("$set_ball"),
             &SystemClauseType::SetCutPointByDefault(_) => clause_name!("$set_cp_by_default"),
             &SystemClauseType::SetDoubleQuotes => clause_name!("$set_double_quotes"),
+            &SystemClauseType::SetMaxTermDepth => clause_name!("$set_max_term_depth"),
+            &SystemClauseType::SetUnknown => clause_name!("$set_unknown"),
             &SystemClauseType::SkipMaxList => clause_name!("$skip_max_list"),
+            &SystemClauseType::Succ => clause_name!("$succ"),
+            &SystemClauseType::Plus => clause_name!("$plus"),
             &SystemClauseType::Sleep => clause_name!("$sleep"),
             &SystemClauseType::SocketClientOpen => clause_name!("$socket_client_open"),
             &SystemClauseType::SocketServerOpen => clause_name!("$socket_server_open"),
@@ -600,6 +665,9 @@ impl SystemClauseType {
     pub(crate) fn from(name: &str, arity: usize) -> Option<SystemClauseType> {
         match (name, arity) {
             ("$abolish_clause", 3) => Some(SystemClauseType::REPL(REPLCodePtr::AbolishClause)),
+            ("$declare_dynamic_predicate", 5) => {
+                Some(SystemClauseType::REPL(REPLCodePtr::DeclareDynamicPredicate))
+            }
             ("$add_dynamic_predicate", 4) => {
                 Some(SystemClauseType::REPL(REPLCodePtr::AddDynamicPredicate))
             }
@@ -629,6 +697,7 @@ impl SystemClauseType {
             ("$check_cp", 1) => Some(SystemClauseType::CheckCutPoint),
             ("$copy_to_lh", 2) => Some(SystemClauseType::CopyToLiftedHeap),
             ("$close", 2) => Some(SystemClauseType::Close),
+            ("$close_output_string", 2) => Some(SystemClauseType::CloseOutputString),
             ("$current_hostname", 1) => Some(SystemClauseType::CurrentHostname),
             ("$current_input", 1) => Some(SystemClauseType::CurrentInput),
             ("$current_output", 1) => Some(SystemClauseType::CurrentOutput),
@@ -661,6 +730,9 @@ impl SystemClauseType {
             ("$put_char", 2) => Some(SystemClauseType::PutChar),
             ("$put_chars", 2) => Some(SystemClauseType::PutChars),
             ("$put_code", 2) => Some(SystemClauseType::PutCode),
+            ("$tab", 2) => Some(SystemClauseType::Tab),
+            ("$downcase_atom", 2) => Some(SystemClauseType::DowncaseAtom),
+            ("$upcase_atom", 2) => Some(SystemClauseType::UpcaseAtom),
             ("$reset_attr_var_state", 0) => Some(SystemClauseType::ResetAttrVarState),
             ("$truncate_if_no_lh_growth", 1) => {
                 Some(SystemClauseType::TruncateIfNoLiftedHeapGrowth)
@@ -673,21 +745,39 @@ impl SystemClauseType {
             ("$get_lh_from_offset", 2) => Some(SystemClauseType::GetLiftedHeapFromOffset),
             ("$get_lh_from_offset_diff", 3) => Some(SystemClauseType::GetLiftedHeapFromOffsetDiff),
             ("$get_double_quotes", 1) => Some(SystemClauseType::GetDoubleQuotes),
+            ("$get_max_integer", 1) => Some(SystemClauseType::GetMaxInteger),
+            ("$get_max_term_depth", 1) => Some(SystemClauseType::GetMaxTermDepth),
+            ("$get_min_integer", 1) => Some(SystemClauseType::GetMinInteger),
+            ("$get_unknown", 1) => Some(SystemClauseType::GetUnknown),
             ("$get_scc_cleaner", 1) => Some(SystemClauseType::GetSCCCleaner),
             ("$halt", 1) => Some(SystemClauseType::Halt),
             ("$head_is_dynamic", 2) => Some(SystemClauseType::HeadIsDynamic),
             ("$install_scc_cleaner", 2) => Some(SystemClauseType::InstallSCCCleaner),
             ("$install_inference_counter", 3) => Some(SystemClauseType::InstallInferenceCounter),
+            ("$init_lh_ground_cache", 1) => Some(SystemClauseType::InitLiftedHeapGroundCache),
             ("$lh_length", 1) => Some(SystemClauseType::LiftedHeapLength),
+            ("$msort", 2) => Some(SystemClauseType::MSort),
+            ("$numlist", 3) => Some(SystemClauseType::NumList),
+            ("$numlist_step", 4) => Some(SystemClauseType::NumListStep),
+            ("$string_code", 3) => Some(SystemClauseType::StringCode),
+            ("$string_chars", 2) => Some(SystemClauseType::StringChars),
+            ("$string_codes", 2) => Some(SystemClauseType::StringCodes),
+            ("$term_hash", 2) => Some(SystemClauseType::TermHash),
             ("$maybe", 0) => Some(SystemClauseType::Maybe),
             ("$cpu_now", 1) => Some(SystemClauseType::CpuNow),
+            ("$memory_statistics", 3) => Some(SystemClauseType::MemoryStatistics),
+            ("$atom_table_statistics", 3) => Some(SystemClauseType::AtomTableStatistics),
+            ("$inference_count", 1) => Some(SystemClauseType::InferenceCount),
             ("$current_time", 1) => Some(SystemClauseType::CurrentTime),
             ("$module_exists", 1) => Some(SystemClauseType::ModuleExists),
+            ("$is_imported_predicate", 4) => Some(SystemClauseType::IsImportedPredicate),
             ("$no_such_predicate", 2) => Some(SystemClauseType::NoSuchPredicate),
             ("$number_to_chars", 2) => Some(SystemClauseType::NumberToChars),
             ("$number_to_codes", 2) => Some(SystemClauseType::NumberToCodes),
             ("$op", 3) => Some(SystemClauseType::OpDeclaration),
             ("$open", 7) => Some(SystemClauseType::Open),
+            ("$open_output_string", 1) => Some(SystemClauseType::OpenOutputString),
+            ("$open_input_string", 2) => Some(SystemClauseType::OpenInputString),
             ("$redo_attr_var_binding", 2) => Some(SystemClauseType::RedoAttrVarBinding),
             ("$remove_call_policy_check", 1) => Some(SystemClauseType::RemoveCallPolicyCheck),
             ("$remove_inference_counter", 2) => Some(SystemClauseType::RemoveInferenceCounter),
@@ -711,16 +801,21 @@ impl SystemClauseType {
             ("$quoted_token", 1) => Some(SystemClauseType::QuotedToken),
             ("$nextEP", 3) => Some(SystemClauseType::NextEP),
             ("$read_query_term", 5) => Some(SystemClauseType::ReadQueryTerm),
-            ("$read_term", 5) => Some(SystemClauseType::ReadTerm),
-            ("$read_term_from_chars", 2) => Some(SystemClauseType::ReadTermFromChars),
+            ("$read_number", 2) => Some(SystemClauseType::ReadNumber),
+            ("$read_term", 6) => Some(SystemClauseType::ReadTerm),
+            ("$read_term_from_chars", 6) => Some(SystemClauseType::ReadTermFromChars),
             ("$reset_block", 1) => Some(SystemClauseType::ResetBlock),
             ("$reset_cont_marker", 0) => Some(SystemClauseType::ResetContinuationMarker),
             ("$return_from_verify_attr", 0) => Some(SystemClauseType::ReturnFromVerifyAttr),
             ("$set_ball", 1) => Some(SystemClauseType::SetBall),
             ("$set_cp_by_default", 1) => Some(SystemClauseType::SetCutPointByDefault(temp_v!(1))),
             ("$set_double_quotes", 1) => Some(SystemClauseType::SetDoubleQuotes),
+            ("$set_max_term_depth", 1) => Some(SystemClauseType::SetMaxTermDepth),
+            ("$set_unknown", 1) => Some(SystemClauseType::SetUnknown),
             ("$set_seed", 1) => Some(SystemClauseType::SetSeed),
             ("$skip_max_list", 4) => Some(SystemClauseType::SkipMaxList),
+            ("$succ", 2) => Some(SystemClauseType::Succ),
+            ("$plus", 3) => Some(SystemClauseType::Plus),
             ("$sleep", 1) => Some(SystemClauseType::Sleep),
             ("$socket_client_open", 8) => Some(SystemClauseType::SocketClientOpen),
             ("$socket_server_open", 3) => Some(SystemClauseType::SocketServerOpen),
@@ -873,6 +873,10 @@ impl<'a, TermMarker: Allocator<'a>> CodeGenerator<TermMarker> {
         conjunct_info.mark_unsafe_vars(unsafe_var_marker, &mut code);
         self.compile_cleanup(&mut code, &conjunct_info, clauses.last().unwrap_or(p1));
 
+        if self.marker.max_reg_allocated() > MAX_ARITY {
+            return Err(CompilationError::ExceededMaxArity);
+        }
+
         Ok(code)
     }
 
@@ -897,7 +901,10 @@ impl<'a, TermMarker: Allocator<'a>> CodeGenerator<TermMarker> {
         UnsafeVarMarker::from_safe_vars(safe_vars)
     }
 
-    pub(crate) fn compile_fact<'b: 'a>(&mut self, term: &'b Term) -> Code {
+    pub(crate) fn compile_fact<'b: 'a>(
+        &mut self,
+        term: &'b Term,
+    ) -> Result<Code, CompilationError> {
         self.update_var_count(post_order_iter(term));
 
         let mut vs = VariableFixtures::new();
@@ -923,8 +930,12 @@ impl<'a, TermMarker: Allocator<'a>> CodeGenerator<TermMarker> {
             }
         }
 
+        if self.marker.max_reg_allocated() > MAX_ARITY {
+            return Err(CompilationError::ExceededMaxArity);
+        }
+
         code.push(proceed!());
-        code
+        Ok(code)
     }
 
     fn compile_query_line(
@@ -1047,7 +1058,7 @@ impl<'a, TermMarker: Allocator<'a>> CodeGenerator<TermMarker> {
             self.global_jmp_by_locs_offset = self.jmp_by_locs.len();
 
             let clause_code = match clause {
-                &PredicateClause::Fact(ref fact, ..) => self.compile_fact(fact),
+                &PredicateClause::Fact(ref fact, ..) => self.compile_fact(fact)?,
                 &PredicateClause::Rule(ref rule, ..) => self.compile_rule(rule)?,
             };
 
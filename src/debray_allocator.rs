@@ -21,6 +21,7 @@ pub(crate) struct DebrayAllocator {
     arity: usize, // 0 if not at head.
     contents: IndexMap<usize, Rc<Var>>,
     in_use: BTreeSet<usize>,
+    max_reg_allocated: usize,
 }
 
 impl DebrayAllocator {
@@ -38,6 +39,12 @@ impl DebrayAllocator {
         }
     }
 
+    #[inline]
+    fn mark_in_use(&mut self, r: usize) {
+        self.in_use.insert(r);
+        self.max_reg_allocated = std::cmp::max(self.max_reg_allocated, r);
+    }
+
     #[inline]
     fn is_in_use(&self, r: usize) -> bool {
         let in_use_range = r <= self.arity && r >= self.arg_c;
@@ -140,7 +147,7 @@ impl DebrayAllocator {
                     self.contents.insert(r.reg_num(), var.clone());
 
                     self.record_register(var, r);
-                    self.in_use.insert(r.reg_num());
+                    self.mark_in_use(r.reg_num());
                 }
             }
             _ => {}
@@ -188,7 +195,7 @@ impl DebrayAllocator {
             }
         }
 
-        self.in_use.insert(final_index);
+        self.mark_in_use(final_index);
         self.temp_lb = final_index + 1;
         final_index
     }
@@ -213,6 +220,7 @@ impl<'a> Allocator<'a> for DebrayAllocator {
             bindings: IndexMap::new(),
             contents: IndexMap::new(),
             in_use: BTreeSet::new(),
+            max_reg_allocated: 0,
         }
     }
 
@@ -262,7 +270,7 @@ impl<'a> Allocator<'a> for DebrayAllocator {
             }
             _ if r.reg_num() == 0 => RegType::Temp(self.alloc_reg_to_non_var()),
             _ => {
-                self.in_use.insert(r.reg_num());
+                self.mark_in_use(r.reg_num());
                 r
             }
         };
@@ -351,7 +359,7 @@ impl<'a> Allocator<'a> for DebrayAllocator {
 
             self.contents.insert(o, var.clone());
             self.record_register(var.clone(), r);
-            self.in_use.insert(o);
+            self.mark_in_use(o);
         }
     }
 
@@ -359,6 +367,7 @@ impl<'a> Allocator<'a> for DebrayAllocator {
         self.bindings.clear();
         self.contents.clear();
         self.in_use.clear();
+        self.max_reg_allocated = 0;
     }
 
     fn reset_contents(&mut self) {
@@ -370,6 +379,10 @@ impl<'a> Allocator<'a> for DebrayAllocator {
         self.arg_c += 1;
     }
 
+    fn max_reg_allocated(&self) -> usize {
+        self.max_reg_allocated
+    }
+
     fn bindings(&self) -> &AllocVarDict {
         &self.bindings
     }
@@ -391,7 +404,7 @@ impl<'a> Allocator<'a> for DebrayAllocator {
                 let r = self.get(var.clone());
 
                 if !r.is_perm() && r.reg_num() == 0 {
-                    self.in_use.insert(idx + 1);
+                    self.mark_in_use(idx + 1);
                     self.contents.insert(idx + 1, var.clone());
                     self.record_register(var.clone(), temp_v!(idx + 1));
                 }
@@ -270,7 +270,7 @@ pub(crate) enum Declaration {
     MetaPredicate(ClauseName, ClauseName, Vec<MetaSpec>), // module name, name, meta-specs
     Module(ModuleDecl),
     NonCountedBacktracking(ClauseName, usize), // name, arity
-    Op(OpDecl),
+    Op(Vec<OpDecl>),
     UseModule(ModuleSource),
     UseQualifiedModule(ModuleSource, IndexSet<ModuleExport>),
 }
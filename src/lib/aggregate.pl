@@ -0,0 +1,71 @@
+:- module(aggregate, [aggregate_all/3]).
+
+:- use_module(library(error)).
+:- use_module(library(iso_ext)).
+
+%% aggregate_all(+Spec, :Goal, -Result)
+%
+% Aggregates all solutions of Goal according to Spec, one of
+% count, sum(Expr) or bag(Template). count and sum(Expr) keep a
+% single running value rather than collecting the solutions.
+
+:- meta_predicate aggregate_all(?, 0, ?).
+
+aggregate_all(Spec, Goal, Result) :-
+    (   var(Spec) ->
+        instantiation_error(aggregate_all/3)
+    ;   aggregate_spec(Spec) ->
+        aggregate_all_(Spec, Goal, Result)
+    ;   domain_error(aggregate_spec, Spec, aggregate_all/3)
+    ).
+
+aggregate_spec(count).
+aggregate_spec(sum(_)).
+aggregate_spec(bag(_)).
+
+aggregate_all_(count, Goal, Count) :-
+    fold_solutions(Goal, aggregate:count_, 0, Count).
+aggregate_all_(sum(Expr), Goal, Sum) :-
+    fold_solutions(Goal, aggregate:sum_(Expr), 0, Sum).
+aggregate_all_(bag(Template), Goal, Bag) :-
+    findall(Template, Goal, Bag).
+
+count_(N0, N) :- N is N0 + 1.
+
+sum_(Expr, S0, S) :- S is S0 + Expr.
+
+% runs Goal to exhaustion, threading a single accumulator through
+% Step after each solution. the accumulator is kept in a global
+% variable keyed by the nesting depth so that nested aggregates
+% don't interfere.
+fold_solutions(Goal, Step, A0, A) :-
+    setup_call_cleanup(enter_aggregate(A0, Key),
+                       (   call(Goal),
+                           fold_step(Key, Step),
+                           false
+                       ;   bb_get(Key, A1)
+                       ),
+                       exit_aggregate),
+    !,
+    A = A1.
+
+fold_step(Key, Step) :-
+    bb_get(Key, A0),
+    call(Step, A0, A),
+    bb_put(Key, A).
+
+enter_aggregate(A0, Key) :-
+    (   bb_get('$aggregate_depth', D0) ->
+        D is D0 + 1
+    ;   D = 0
+    ),
+    bb_put('$aggregate_depth', D),
+    number_chars(D, Cs),
+    atom_chars(Suffix, Cs),
+    atom_concat('$aggregate_', Suffix, Key),
+    bb_put(Key, A0).
+
+exit_aggregate :-
+    bb_get('$aggregate_depth', D0),
+    D is D0 - 1,
+    bb_put('$aggregate_depth', D).
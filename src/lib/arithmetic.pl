@@ -1,6 +1,7 @@
 :- module(arithmetic, [expmod/4, lsb/2, msb/2, number_to_rational/2,
                        number_to_rational/3,
-                       rational_numerator_denominator/3]).
+                       rational_numerator_denominator/3,
+                       plus/3, succ/2]).
 
 :- use_module(library(charsio), [write_term_to_chars/3]).
 :- use_module(library(error)).
@@ -52,6 +53,12 @@ msb_(X, M, N) :-
     M1 is M + 1,
     msb_(X1, M1, N).
 
+succ(X, Y) :-
+    '$succ'(X, Y).
+
+plus(X, Y, Z) :-
+    '$plus'(X, Y, Z).
+
 number_to_rational(Real, Fraction) :-
     (   var(Real) -> instantiation_error(number_to_rational/2)
     ;   integer(Real) -> Fraction is Real rdiv 1
@@ -1,8 +1,10 @@
 :- module(atts, [op(1199, fx, attribute),
                  call_residue_vars/2,
+                 copy_term/3,
                  term_attributed_variables/2]).
 
 :- use_module(library(dcgs)).
+:- use_module(library('$project_atts')).
 :- use_module(library(terms)).
 
 /* represent the list of attributes belonging to a variable,
@@ -1,4 +1,5 @@
-:- module(between, [between/3, gen_int/1, gen_nat/1, numlist/2, numlist/3, repeat/1]).
+:- module(between, [between/3, gen_int/1, gen_nat/1, numlist/2, numlist/3,
+                    numlist_step/4, repeat/1]).
 
 %% TODO: numlist/5.
 
@@ -7,15 +8,24 @@
 
 between(Lower, Upper, X) :-
     must_be(integer, Lower),
-    must_be(integer, Upper),
+    (   infinite(Upper) -> true
+    ;   must_be(integer, Upper)
+    ),
     can_be(integer, X),
     (   nonvar(X) ->
         Lower =< X,
-        X =< Upper
-    ;   compare(Ord, Lower, Upper),
+        (   integer(Upper) -> X =< Upper
+        ;   true
+        )
+    ;   integer(Upper) ->
+        compare(Ord, Lower, Upper),
         between_(Ord, Lower, Upper, X)
+    ;   enumerate_nats(Lower, X)
     ).
 
+infinite(Upper) :- Upper == inf.
+infinite(Upper) :- Upper == infinite.
+
 between_(<, Lower0, Upper, X) :-
     (   X = Lower0
     ;   Lower1 is Lower0 + 1,
@@ -106,4 +116,10 @@ gen_ints(L, U) :-
     L =< U.
 
 numlist(Lower, Upper, List) :-
-    gen_ints(Lower, Upper), findall(X, between(Lower, Upper, X), List).
+    (  integer(Lower), integer(Upper) -> true
+    ;  gen_ints(Lower, Upper)
+    ),
+    '$numlist'(Lower, Upper, List).
+
+numlist_step(Lower, Step, Upper, List) :-
+    '$numlist_step'(Lower, Step, Upper, List).
@@ -17,16 +17,18 @@
                      number_chars/2, number_codes/2, once/1, op/3,
                      open/3, open/4, peek_byte/1, peek_byte/2,
                      peek_char/1, peek_char/2, peek_code/1,
-                     peek_code/2, put_byte/1, put_byte/2, put_code/1,
-                     put_code/2, put_char/1, put_char/2, read_term/2,
-                     read_term/3, repeat/0, retract/1, retractall/1,
-                     set_prolog_flag/2, set_input/1,
+                     peek_code/2, print/1, put_byte/1, put_byte/2,
+                     put_code/1, put_code/2, put_char/1, put_char/2,
+                     read_clause/3, read_term/2, read_term/3,
+                     repeat/0, retract/1,
+                     retractall/1, set_prolog_flag/2, set_input/1,
                      set_stream_position/2, set_output/1, setof/3,
                      stream_property/2, sub_atom/5, subsumes_term/2,
-                     term_variables/2, throw/1, true/0,
+                     tab/1, tab/2, term_variables/2, throw/1, true/0,
                      unify_with_occurs_check/2, write/1, write/2,
                      write_canonical/1, write_canonical/2,
-                     write_term/2, write_term/3, writeq/1, writeq/2]).
+                     write_term/2, write_term/3, writeln/1, writeq/1,
+                     writeq/2]).
 
 
 % the maximum arity flag. needs to be replaced with
@@ -59,9 +61,9 @@ call(G, A, B, C, D, E) :- '$call'(G, A, B, C, D, E).
 
 call(G, A, B, C, D, E, F) :- '$call'(G, A, B, C, D, E, F).
 
-call(G, A, B, C, D, E, F, G) :- '$call'(G, A, B, C, D, E, F, G).
+call(G, A, B, C, D, E, F, H) :- '$call'(G, A, B, C, D, E, F, H).
 
-call(G, A, B, C, D, E, F, G, H) :- '$call'(G, A, B, C, D, E, F, G, H).
+call(G, A, B, C, D, E, F, H, I) :- '$call'(G, A, B, C, D, E, F, H, I).
 
 
 Module : Predicate :-
@@ -126,18 +128,31 @@ Module : Predicate :-
 
 % flags.
 
+% integers are unbounded, so bounded is false and max_integer and
+% min_integer report the bounds of the fixnums, the integers that are
+% held in a single machine word (isize). Integers outside of them are
+% represented as bignums.
+
 current_prolog_flag(Flag, false) :- Flag == bounded, !.
 current_prolog_flag(bounded, false).
 current_prolog_flag(Flag, toward_zero) :- Flag == integer_rounding_function, !.
 current_prolog_flag(integer_rounding_function, toward_zero).
 current_prolog_flag(Flag, Value) :- Flag == double_quotes, !, '$get_double_quotes'(Value).
 current_prolog_flag(double_quotes, Value) :- '$get_double_quotes'(Value).
-current_prolog_flag(Flag, _) :- Flag == max_integer, !, '$fail'.
-current_prolog_flag(Flag, _) :- Flag == min_integer, !, '$fail'.
+current_prolog_flag(Flag, Value) :- Flag == max_integer, !, '$get_max_integer'(Value).
+current_prolog_flag(max_integer, Value) :- '$get_max_integer'(Value).
+current_prolog_flag(Flag, Value) :- Flag == min_integer, !, '$get_min_integer'(Value).
+current_prolog_flag(min_integer, Value) :- '$get_min_integer'(Value).
 current_prolog_flag(Flag, OccursCheckEnabled) :-
     Flag == occurs_check,
     !,
     '$is_sto_enabled'(OccursCheckEnabled).
+current_prolog_flag(occurs_check, OccursCheckEnabled) :-
+    '$is_sto_enabled'(OccursCheckEnabled).
+current_prolog_flag(Flag, Value) :- Flag == max_term_depth, !, '$get_max_term_depth'(Value).
+current_prolog_flag(max_term_depth, Value) :- '$get_max_term_depth'(Value).
+current_prolog_flag(Flag, Value) :- Flag == unknown, !, '$get_unknown'(Value).
+current_prolog_flag(unknown, Value) :- '$get_unknown'(Value).
 current_prolog_flag(Flag, _) :-
     atom(Flag),
     throw(error(domain_error(prolog_flag, Flag), current_prolog_flag/2)). % 8.17.2.3 b
@@ -148,14 +163,19 @@ current_prolog_flag(Flag, _) :-
 set_prolog_flag(Flag, Value) :-
     (var(Flag) ; var(Value)),
     throw(error(instantiation_error, set_prolog_flag/2)). % 8.17.1.3 a, b
-set_prolog_flag(bounded, false) :- !. % 7.11.1.1
-set_prolog_flag(bounded, true)  :- !, '$fail'. % 7.11.1.1
+set_prolog_flag(bounded, Value) :-
+    lists:member(Value, [true, false]), !,
+    throw(error(permission_error(modify, flag, bounded), set_prolog_flag/2)). % 7.11.1.1
 set_prolog_flag(bounded, Value) :-
     throw(error(domain_error(flag_value, bounded + Value), set_prolog_flag/2)). % 8.17.1.3 e
-set_prolog_flag(max_integer, Value) :- integer(Value), !, '$fail'. % 7.11.1.2
+set_prolog_flag(max_integer, Value) :-
+    integer(Value), !,
+    throw(error(permission_error(modify, flag, max_integer), set_prolog_flag/2)). % 7.11.1.2
 set_prolog_flag(max_integer, Value) :-
     throw(error(domain_error(flag_value, max_integer + Value), set_prolog_flag/2)). % 8.17.1.3 e
-set_prolog_flag(min_integer, Value) :- integer(Value), !, '$fail'. % 7.11.1.3
+set_prolog_flag(min_integer, Value) :-
+    integer(Value), !,
+    throw(error(permission_error(modify, flag, min_integer), set_prolog_flag/2)). % 7.11.1.3
 set_prolog_flag(min_integer, Value) :-
     throw(error(domain_error(flag_value, min_integer + Value), set_prolog_flag/2)). % 8.17.1.3 e
 set_prolog_flag(integer_rounding_function, down) :- !. % 7.11.1.4
@@ -174,6 +194,21 @@ set_prolog_flag(occurs_check, false) :-
     !, '$set_nsto_as_unify'.
 set_prolog_flag(occurs_check, error) :-
     !, '$set_sto_with_error_as_unify'.
+set_prolog_flag(occurs_check, Value) :-
+    throw(error(domain_error(flag_value, occurs_check + Value),
+		        set_prolog_flag/2)). % 8.17.1.3 e
+set_prolog_flag(max_term_depth, Value) :-
+    integer(Value), Value > 0, !,
+    '$set_max_term_depth'(Value).
+set_prolog_flag(max_term_depth, Value) :-
+    throw(error(domain_error(flag_value, max_term_depth + Value),
+		        set_prolog_flag/2)). % 8.17.1.3 e
+set_prolog_flag(unknown, Value) :-
+    lists:member(Value, [error, fail, warning]), !,
+    '$set_unknown'(Value). % 7.11.2.4
+set_prolog_flag(unknown, Value) :-
+    throw(error(domain_error(flag_value, unknown + Value),
+		        set_prolog_flag/2)). % 8.17.1.3 e
 set_prolog_flag(double_quotes, Value) :-
     throw(error(domain_error(flag_value, double_quotes + Value),
 		        set_prolog_flag/2)). % 8.17.1.3 e
@@ -183,6 +218,14 @@ set_prolog_flag(Flag, _) :-
 set_prolog_flag(Flag, _) :-
     throw(error(type_error(atom, Flag), set_prolog_flag/2)). % 8.17.1.3 c
 
+% the machine calls this in place of an undefined procedure when the
+% unknown flag is set to warning.
+'$warn_unknown_procedure'(PI) :-
+    write('Warning: unknown procedure '),
+    writeq(PI),
+    nl,
+    false.
+
 % control operators.
 
 fail :- '$fail'.
@@ -524,6 +567,25 @@ writeq(Term) :-
 writeq(Stream, Term) :-
     '$write_term'(Stream, Term, false, true, true, [], 0).
 
+writeln(Term) :-
+    write(Term),
+    nl.
+
+% print/1 hands Term to user:portray/1 if it is defined, and writes
+% it as by writeq/1 if there is no such hook or the hook fails. Only
+% Term itself is offered to the hook, not its subterms.
+print(Term) :-
+    (  portray_hook(Term) ->
+       true
+    ;  writeq(Term)
+    ).
+
+% succeeds if user:portray/1 is defined and has printed Term. format's
+% ~p directive relies on it as well.
+portray_hook(Term) :-
+    \+ '$no_such_predicate'(user, portray(_)),
+    user:portray(Term).
+
 select_rightmost_options([Option-Value | OptionPairs], OptionValues) :-
     (  pairs:same_key(Option, OptionPairs, OtherValues, _),
        OtherValues == []  ->
@@ -536,10 +598,13 @@ select_rightmost_options([], []).
 
 
 parse_read_term_options(Options, OptionValues, Stub) :-
-    DefaultOptions = [singletons-_, variables-_, variable_names-_],
+    DefaultOptions = [layout-[], singletons-_, variables-_, variable_names-_],
     parse_options_list(Options, builtins:parse_read_term_options_, DefaultOptions, OptionValues, Stub).
 
 
+% layout(Layout) is wrapped in a list so that '$read_term' only builds
+% the layout of the term read if it was asked for.
+parse_read_term_options_(layout(Layout), layout-[Layout]).
 parse_read_term_options_(singletons(Vars), singletons-Vars).
 parse_read_term_options_(variables(Vars), variables-Vars).
 parse_read_term_options_(variable_names(Vars), variable_names-Vars).
@@ -549,13 +614,23 @@ parse_read_term_options_(E,_) :-
 
 
 read_term(Stream, Term, Options) :-
-    parse_read_term_options(Options, [Singletons, VariableNames, Variables], read_term/3),
-    '$read_term'(Stream, Term, Singletons, Variables, VariableNames).
+    parse_read_term_options(Options, [Layout, Singletons, VariableNames, Variables], read_term/3),
+    '$read_term'(Stream, Term, Singletons, Variables, VariableNames, Layout).
 
 read_term(Term, Options) :-
     current_input(Stream),
     read_term(Stream, Term, Options).
 
+% read_clause/3 accepts the options of read_term/3. The layout(Layout)
+% option unifies Layout with the source layout of Clause, a term
+% span(StartLine, StartCol, EndLine, EndCol, Args) where Args is the
+% list of the layouts of the arguments of Clause. Lines and columns
+% are counted from 0 and the end of each span lies just past its last
+% character.
+read_clause(Stream, Clause, Options) :-
+    parse_read_term_options(Options, [Layout, Singletons, VariableNames, Variables], read_clause/3),
+    '$read_term'(Stream, Clause, Singletons, Variables, VariableNames, Layout).
+
 
 % term_variables.
 
@@ -633,6 +708,7 @@ truncate_lh_to(LhLength) :- '$truncate_lh_to'(LhLength).
 findall(Template, Goal, Solutions) :-
     error:can_be(list, Solutions),
     '$lh_length'(LhLength),
+    '$init_lh_ground_cache'(LhLength),
     '$call_with_default_policy'(
         catch(builtins:'$iterate_find_all'(Template, Goal, Solutions, LhLength),
 			  Error,
@@ -657,6 +733,7 @@ findall(Template, Goal, Solutions0, Solutions1) :-
     error:can_be(list, Solutions0),
     error:can_be(list, Solutions1),
     '$lh_length'(LhLength),
+    '$init_lh_ground_cache'(LhLength),
     '$call_with_default_policy'(
         catch(builtins:'$iterate_find_all_diff'(Template, Goal, Solutions0,
 							                    Solutions1, LhLength),
@@ -786,16 +863,42 @@ setof(Template, Goal, Solution) :-
     ).
 
 
+% the meta_predicate declaration qualifies the head with the calling
+% module, so clause/2 looks up the clauses visible from there.
+:- meta_predicate clause(0, ?).
+
 clause(H, B) :-
+    clause_(H, B).
+
+clause_(H, B) :-
     (  var(H) ->
        throw(error(instantiation_error, clause/2))
     ;  callable(H), functor(H, Name, Arity) ->
        (  Name == (:),
           Arity =:= 2 ->
-		  arg(1, H, Module),
-		  arg(2, H, F),
-		  '$module_clause'(F, B, Module)
-	   ;  '$head_is_dynamic'(user, H) ->
+          arg(1, H, Module),
+          arg(2, H, F),
+          (  var(Module) ->
+             throw(error(instantiation_error, clause/2))
+          ;  nonvar(F),
+             F = _:_ ->
+             clause_(F, B)
+          ;  Module == user ->
+             user_clause(F, B)
+          ;  '$module_clause'(F, B, Module)
+          )
+       ;  user_clause(H, B)
+       )
+    ;  throw(error(type_error(callable, H), clause/2))
+    ).
+
+% the '$clause' facts of predicates in user are kept in builtins,
+% where they can be called unqualified.
+user_clause(H, B) :-
+    (  var(H) ->
+       throw(error(instantiation_error, clause/2))
+    ;  callable(H), functor(H, Name, Arity) ->
+       (  '$head_is_dynamic'(user, H) ->
           '$clause_body_is_valid'(B),
 		  '$clause'(H, B)
        ;  '$no_such_predicate'(user, H) ->  %% '$no_such_predicate' fails if
@@ -845,12 +948,29 @@ asserta_clause(Head, Body) :-
 :- meta_predicate asserta(0).
 
 asserta(Clause) :-
-    (  Clause \= (_ :- _) ->
+    clause_head_and_body(Clause, Head, Body),
+    asserta_clause(Head, Body).
+
+% splits a clause passed to asserta/1, assertz/1 or retract/1 into its
+% head and body. the meta_predicate declarations qualify the clause as a
+% whole, so the module qualification is moved onto the head unless the
+% head is qualified already.
+clause_head_and_body(Clause, Head, Body) :-
+    (  var(Clause) ->
        Head = Clause,
-       Body = true,
-       asserta_clause(Head, Body)
+       Body = true
+    ;  Clause = Module:Clause0,
+       nonvar(Clause0),
+       Clause0 = (Head0 :- Body) ->
+       (  nonvar(Head0),
+          Head0 = _:_ ->
+          Head = Head0
+       ;  Head = Module:Head0
+       )
     ;  Clause = (Head :- Body) ->
-       asserta_clause(Head, Body)
+       true
+    ;  Head = Clause,
+       Body = true
     ).
 
 module_assertz_clause(Head, Body, Module) :-
@@ -895,13 +1015,8 @@ assertz_clause(Head, Body) :-
 :- meta_predicate assertz(0).
 
 assertz(Clause) :-
-    (  Clause \= (_ :- _) ->
-       Head = Clause,
-       Body = true,
-       assertz_clause(Head, Body)
-    ;  Clause = (Head :- Body) ->
-       assertz_clause(Head, Body)
-    ).
+    clause_head_and_body(Clause, Head, Body),
+    assertz_clause(Head, Body).
 
 
 module_retract_clauses([Clause|Clauses0], Head, Body, Name, Arity, Module) :-
@@ -984,13 +1099,8 @@ retract_clause(Head, Body) :-
 :- meta_predicate retract(0).
 
 retract(Clause) :-
-    (  Clause \= (_ :- _) ->
-       Head = Clause,
-       Body = true,
-       retract_clause(Head, Body)
-    ;  Clause = (Head :- Body) ->
-       retract_clause(Head, Body)
-    ).
+    clause_head_and_body(Clause, Head, Body),
+    retract_clause(Head, Body).
 
 
 :- meta_predicate retractall(0).
@@ -998,7 +1108,19 @@ retract(Clause) :-
 retractall(Head) :-
    retract((Head :- _)),
    false.
-retractall(_).
+retractall(Head) :-
+   (  Head = Module:Head1 ->
+      true
+   ;  Module = user,
+      Head1 = Head
+   ),
+   % retract/1 has thrown for anything but a dynamic or undefined
+   % predicate by now. an undefined one is created as dynamic.
+   (  '$head_is_dynamic'(Module, Head1) ->
+      true
+   ;  functor(Head1, Name, Arity),
+      '$declare_dynamic_predicate'(Module, Name, Arity, retractall, 1)
+   ).
 
 
 module_abolish(Pred, Module) :-
@@ -1065,9 +1187,17 @@ abolish(Pred) :-
     '$iterate_db_refs'(NextRef, Name/Arity).
 
 
+can_be_predicate_indicator(Name/Arity) :-
+    (  var(Name) ; atom(Name)  ),
+    (  var(Arity) ; integer(Arity)  ),
+    !.
+
 current_predicate(Pred) :-
-    (  nonvar(Pred), Pred \= _ / _
+    (  nonvar(Pred), \+ can_be_predicate_indicator(Pred)
     -> throw(error(type_error(predicate_indicator, Pred), current_predicate/1))
+    ;  ground(Pred)
+    -> '$get_next_db_ref'(Ref, _),
+       once('$iterate_db_refs'(Ref, Pred))
     ;  '$get_next_db_ref'(Ref, _),
        '$iterate_db_refs'(Ref, Pred)
     ).
@@ -1272,8 +1402,13 @@ char_code(Char, Code) :-
           '$char_code'(Char, Code)
        ;  throw(error(type_error(integer, Code), char_code/2))
        )
-    ;  atom_length(Char, 1) ->
-       '$char_code'(Char, Code)
+    ;  atom(Char), atom_length(Char, 1) ->
+       (  var(Code) ->
+          '$char_code'(Char, Code)
+       ;  integer(Code) ->
+          '$char_code'(Char, Code)
+       ;  throw(error(type_error(integer, Code), char_code/2))
+       )
     ;  throw(error(type_error(character, Char), char_code/2))
     ).
 
@@ -1300,6 +1435,18 @@ must_be_number(N, PI) :-
     ;  throw(error(instantiation_error, PI))
     ).
 
+% like must_be_number/2 but also admitting rationals, which are
+% written as N rdiv D by number_chars/2 and number_codes/2.
+can_be_numeric(N, PI) :-
+    (  var(N) -> true
+    ;  must_be_numeric(N, PI)
+    ).
+
+must_be_numeric(N, PI) :-
+    (  nonvar(N), rational(N) -> true
+    ;  must_be_number(N, PI)
+    ).
+
 can_be_chars_or_vars(Cs, _) :- var(Cs), !.
 can_be_chars_or_vars(Cs, PI) :- chars_or_vars(Cs, PI).
 
@@ -1335,11 +1482,11 @@ codes_or_vars([C|Cs], PI) :-
 
 number_chars(N, Chs) :-
    (  ground(Chs)
-   -> can_be_number(N, number_chars/2),
+   -> can_be_numeric(N, number_chars/2),
       can_be_list(Chs, number_chars/2),
       '$chars_to_number'(Chs, Nx),
       Nx = N
-   ;  must_be_number(N, number_chars/2),
+   ;  must_be_numeric(N, number_chars/2),
       (  var(Chs) -> true
       ;  can_be_list(Chs, number_chars/2)
       ,  chars_or_vars(Chs, number_chars/2)
@@ -1350,11 +1497,11 @@ number_chars(N, Chs) :-
 
 number_codes(N, Chs) :-
    (  ground(Chs)
-   -> can_be_number(N, number_codes/2),
+   -> can_be_numeric(N, number_codes/2),
       can_be_list(Chs, number_codes/2),
       '$codes_to_number'(Chs, Nx),
       Nx = N
-   ;  must_be_number(N, number_codes/2),
+   ;  must_be_numeric(N, number_codes/2),
       (  var(Chs) -> true
       ;  can_be_list(Chs, number_codes/2)
       ,  codes_or_vars(Chs, number_codes/2)
@@ -1482,6 +1629,14 @@ put_char(S, C) :-
     '$put_char'(S, C).
 
 
+tab(N) :-
+    current_output(S),
+    '$tab'(S, N).
+
+tab(S, N) :-
+    '$tab'(S, N).
+
+
 put_byte(C) :-
     current_output(S),
     '$put_byte'(S, C).
@@ -1,8 +1,30 @@
 :- module(charsio, [char_type/2,
                     chars_utf8bytes/2,
                     get_single_char/1,
+                    open_chars_stream/2,
                     read_line_to_chars/3,
+                    read_number/2,
+                    read_string/3,
                     read_term_from_chars/2,
+                    read_term_from_chars/3,
+                    read_term_from_atom/3,
+                    atom_number/2,
+                    atom_string/2,
+                    number_string/2,
+                    atomic_list_concat/2,
+                    atomic_list_concat/3,
+                    split_string/4,
+                    string_chars/2,
+                    string_code/3,
+                    string_codes/2,
+                    string_to_atom/2,
+                    sub_string/5,
+                    upcase_atom/2,
+                    downcase_atom/2,
+                    term_string/2,
+                    text_concat/3,
+                    term_to_atom/2,
+                    atom_to_term/3,
                     write_term_to_chars/3,
                     chars_base64/3]).
 
@@ -115,18 +137,28 @@ get_single_char(C) :-
 
 
 read_term_from_chars(Chars, Term) :-
+    read_term_from_chars_(Chars, Term, _, _, _, [], read_term_from_chars/2).
+
+read_term_from_chars(Chars, Term, Options) :-
+    builtins:parse_read_term_options(Options,
+                                     [Layout, Singletons, VariableNames, Variables],
+                                     read_term_from_chars/3),
+    read_term_from_chars_(Chars, Term, Singletons, Variables, VariableNames,
+                          Layout, read_term_from_chars/3).
+
+read_term_from_chars_(Chars, Term, Singletons, Variables, VariableNames, Layout, PI) :-
     (  var(Chars) ->
-       instantiation_error(read_term_from_chars/2)
+       instantiation_error(PI)
     ;  nonvar(Term) ->
-       throw(error(uninstantiation_error(Term), read_term_from_chars/2))
+       throw(error(uninstantiation_error(Term), PI))
     ;  '$skip_max_list'(_, -1, Chars, Chars0),
        Chars0 == [],
        partial_string(Chars) ->
        true
     ;
-       type_error(complete_string, Chars, read_term_from_chars/2)
+       type_error(complete_string, Chars, PI)
     ),
-    '$read_term_from_chars'(Chars, Term).
+    '$read_term_from_chars'(Chars, Term, Singletons, Variables, VariableNames, Layout).
 
 
 write_term_to_chars(_, Options, _) :-
@@ -144,6 +176,372 @@ write_term_to_chars(Term, Options, Chars) :-
     extend_var_list(Vars, VNNames, NewVarNames, numbervars),
     '$write_term_to_chars'(Chars, Term, IgnoreOps, NumberVars, Quoted, NewVarNames, MaxDepth).
 
+% term_to_atom(?Term, ?Atom)
+%
+% If Atom is instantiated, it is parsed and the result unified with
+% Term. Otherwise Atom is Term written as by writeq/1.
+term_to_atom(Term, Atom) :-
+    (  var(Atom) ->
+       write_term_to_chars(Term, [quoted(true)], Chars),
+       atom_chars(Atom, Chars)
+    ;  atom(Atom) ->
+       atom_to_term_(Atom, Term, _)
+    ;  type_error(atom, Atom, term_to_atom/2)
+    ).
+
+% atom_to_term(+Atom, -Term, -Bindings)
+%
+% Parses Atom as a term, unifying Bindings with the list of
+% Name = Var pairs of the variables named in Atom.
+atom_to_term(Atom, Term, Bindings) :-
+    (  var(Atom) ->
+       instantiation_error(atom_to_term/3)
+    ;  atom(Atom) ->
+       atom_to_term_(Atom, Term, Bindings)
+    ;  type_error(atom, Atom, atom_to_term/3)
+    ).
+
+% read_term_from_atom(+Atom, -Term, +Options)
+%
+% Parses Atom as a term, accepting the options of read_term/2.
+read_term_from_atom(Atom, Term, Options) :-
+    (  var(Atom) ->
+       instantiation_error(read_term_from_atom/3)
+    ;  atom(Atom) ->
+       atom_chars(Atom, Chars0),
+       append(Chars0, " .", Chars),
+       read_term_from_chars(Chars, Term0, Options),
+       Term = Term0
+    ;  type_error(atom, Atom, read_term_from_atom/3)
+    ).
+
+atom_to_term_(Atom, Term, Bindings) :-
+    atom_chars(Atom, Chars0),
+    chars_to_term_(Chars0, Term, Bindings).
+
+chars_to_term_(Chars0, Term, Bindings) :-
+    append(Chars0, " .", Chars),
+    read_term_from_chars(Chars, Term0, [variable_names(Bindings)]),
+    Term = Term0.
+
+% atom_number(?Atom, ?Number)
+%
+% If Atom is instantiated, it is parsed as a number, failing if it
+% isn't one. Otherwise Atom is the canonical text of Number.
+atom_number(Atom, Number) :-
+    (  var(Atom) ->
+       (  var(Number) ->
+          instantiation_error(atom_number/2)
+       ;  number(Number) ->
+          number_chars(Number, Chars),
+          atom_chars(Atom, Chars)
+       ;  rational(Number) ->
+          number_chars(Number, Chars),
+          atom_chars(Atom, Chars)
+       ;  type_error(number, Number, atom_number/2)
+       )
+    ;  atom(Atom) ->
+       atom_chars(Atom, Chars),
+       catch(number_chars(Number0, Chars), error(syntax_error(_), _), false),
+       Number = Number0
+    ;  type_error(atom, Atom, atom_number/2)
+    ).
+
+% number_string(?Number, ?String)
+%
+% Like atom_number/2, but with the text of Number held in a string.
+number_string(Number, String) :-
+    (  var(String) ->
+       (  var(Number) ->
+          instantiation_error(number_string/2)
+       ;  number(Number) ->
+          number_chars(Number, String)
+       ;  rational(Number) ->
+          number_chars(Number, String)
+       ;  type_error(number, Number, number_string/2)
+       )
+    ;  must_be(list, String),
+       catch(number_chars(Number0, String), error(syntax_error(_), _), false),
+       Number = Number0
+    ).
+
+% atomic_list_concat(+List, -Atom)
+%
+% Atom is the concatenation of the text of the atomic elements of
+% List.
+atomic_list_concat(List, Atom) :-
+    (  atomic_list(List, atomic_list_concat/2) ->
+       join_atomics(List, [], Chars),
+       atom_chars(Atom0, Chars),
+       Atom = Atom0
+    ;  instantiation_error(atomic_list_concat/2)
+    ).
+
+% atomic_list_concat(?List, +Separator, ?Atom)
+%
+% As atomic_list_concat/2, but with Separator between the elements.
+% If List is not a list of atomics but Atom is instantiated, Atom is
+% instead split at each occurrence of Separator.
+atomic_list_concat(List, Separator, Atom) :-
+    (  var(Separator) ->
+       instantiation_error(atomic_list_concat/3)
+    ;  atomic(Separator) ->
+       atomic_chars(Separator, SepChars)
+    ;  type_error(atomic, Separator, atomic_list_concat/3)
+    ),
+    (  atomic_list(List, atomic_list_concat/3) ->
+       join_atomics(List, SepChars, Chars),
+       atom_chars(Atom0, Chars),
+       Atom = Atom0
+    ;  var(Atom) ->
+       instantiation_error(atomic_list_concat/3)
+    ;  SepChars == [] ->
+       domain_error(non_empty_atom, Separator, atomic_list_concat/3)
+    ;  atomic(Atom) ->
+       atomic_chars(Atom, Chars),
+       split_atomic(Chars, SepChars, Atoms),
+       List = Atoms
+    ;  type_error(atomic, Atom, atomic_list_concat/3)
+    ).
+
+% succeeds if List is a list of atomics, fails if it is partial or
+% contains variables, and throws if it contains compound terms.
+atomic_list(List, PI) :-
+    '$skip_max_list'(_, -1, List, Tail),
+    Tail == [],
+    \+ ( member(E, List), var(E) ),
+    (  member(E, List), \+ atomic(E) ->
+       type_error(atomic, E, PI)
+    ;  true
+    ).
+
+atomic_chars(Atomic, Chars) :-
+    write_term_to_chars(Atomic, [], Chars).
+
+join_atomics([], _, []).
+join_atomics([A|As], SepChars, Chars) :-
+    atomic_chars(A, Chars0),
+    append(Chars0, Chars1, Chars),
+    join_atomics_(As, SepChars, Chars1).
+
+join_atomics_([], _, []).
+join_atomics_([A|As], SepChars, Chars) :-
+    append(SepChars, Chars0, Chars),
+    join_atomics([A|As], SepChars, Chars0).
+
+split_atomic(Chars, SepChars, [Atom|Atoms]) :-
+    (  append(Field, Chars0, Chars),
+       append(SepChars, Chars1, Chars0) ->
+       atom_chars(Atom, Field),
+       split_atomic(Chars1, SepChars, Atoms)
+    ;  atom_chars(Atom, Chars),
+       Atoms = []
+    ).
+
+% split_string(+String, +SepChars, +PadChars, -SubStrings)
+%
+% Splits String at each character of SepChars, then strips the
+% characters of PadChars from both ends of every substring. As in
+% SWI-Prolog, an empty SepChars leaves String whole, so that
+% split_string(S, "", " ", [T]) trims S, and separators that are
+% also pad characters collapse into one.
+split_string(String, SepChars, PadChars, SubStrings) :-
+    must_be(list, String),
+    must_be(list, SepChars),
+    must_be(list, PadChars),
+    strip_pad(PadChars, String, String1),
+    split_fields(String1, SepChars, PadChars, SubStrings0),
+    SubStrings = SubStrings0.
+
+split_fields(Cs0, SepChars, PadChars, [Field|Fields]) :-
+    drop_pad(Cs0, PadChars, Cs1),
+    split_field(Cs1, SepChars, Field0, Cs),
+    strip_pad(PadChars, Field0, Field),
+    (  Cs = [_|Cs2] ->
+       split_fields(Cs2, SepChars, PadChars, Fields)
+    ;  Fields = []
+    ).
+
+split_field([], _, [], []).
+split_field([C|Cs0], SepChars, Field, Cs) :-
+    (  memberchk(C, SepChars) ->
+       Field = [],
+       Cs = [C|Cs0]
+    ;  Field = [C|Field0],
+       split_field(Cs0, SepChars, Field0, Cs)
+    ).
+
+strip_pad(PadChars, Cs0, Cs) :-
+    drop_pad(Cs0, PadChars, Cs1),
+    reverse(Cs1, Cs2),
+    drop_pad(Cs2, PadChars, Cs3),
+    reverse(Cs3, Cs).
+
+drop_pad([], _, []).
+drop_pad([C|Cs0], PadChars, Cs) :-
+    (  memberchk(C, PadChars) ->
+       drop_pad(Cs0, PadChars, Cs)
+    ;  Cs = [C|Cs0]
+    ).
+
+% string_code(+Index, +String, -Code)
+%
+% Code is the character code at the 1-based Index of String. Fails
+% if Index is out of range.
+string_code(Index, String, Code) :-
+    must_be(integer, Index),
+    must_be(list, String),
+    '$string_code'(Index, String, Code).
+
+% string_chars(?String, ?Chars)
+%
+% Chars is the list of characters of String. At least one of the two
+% arguments must be instantiated.
+string_chars(String, Chars) :-
+    (  var(String) ->
+       must_be(list, Chars)
+    ;  must_be(list, String)
+    ),
+    '$string_chars'(String, Chars).
+
+% string_codes(?String, ?Codes)
+%
+% Codes is the list of character codes of String.
+string_codes(String, Codes) :-
+    (  var(String) ->
+       must_be(list, Codes)
+    ;  must_be(list, String)
+    ),
+    '$string_codes'(String, Codes).
+
+% atom_string(?Atom, ?String)
+%
+% String holds the text of Atom, which may be any atomic term. Numbers
+% are converted by their canonical text. If Atom is unbound, it is
+% the atom with the characters of String.
+atom_string(Atom, String) :-
+    atom_string_(Atom, String, atom_string/2).
+
+% string_to_atom(?String, ?Atom)
+%
+% Like atom_string/2, with the arguments swapped.
+string_to_atom(String, Atom) :-
+    atom_string_(Atom, String, string_to_atom/2).
+
+atom_string_(Atom, String, PI) :-
+    (  var(Atom) ->
+       (  var(String) ->
+          instantiation_error(PI)
+       ;  must_be(list, String),
+          atom_chars(Atom, String)
+       )
+    ;  atomic(Atom) ->
+       atomic_chars(Atom, Chars),
+       String = Chars
+    ;  type_error(atomic, Atom, PI)
+    ).
+
+% text_concat(?Text1, ?Text2, ?Text3)
+%
+% Text3 is the concatenation of Text1 and Text2, which are atoms,
+% numbers or strings, [] being taken as the empty string. If Text1 and
+% Text2 are given, Text3 is a string if either of them is one and an
+% atom otherwise. Else Text3 must be given and its decompositions are
+% enumerated on backtracking, with the unbound parts of the type of
+% Text3.
+text_concat(Text1, Text2, Text3) :-
+    (  nonvar(Text1), nonvar(Text2) ->
+       text_chars(Text1, Chars1, Type1),
+       text_chars(Text2, Chars2, Type2),
+       append(Chars1, Chars2, Chars3),
+       (  ( Type1 == string ; Type2 == string ) ->
+          Text3 = Chars3
+       ;  atom_chars(Atom, Chars3),
+          Text3 = Atom
+       )
+    ;  var(Text3) ->
+       instantiation_error(text_concat/3)
+    ;  text_chars(Text3, Chars3, Type),
+       (  nonvar(Text1) ->
+          text_chars(Text1, Chars1, _),
+          append(Chars1, Chars2, Chars3),
+          chars_text(Type, Chars2, Text2)
+       ;  nonvar(Text2) ->
+          text_chars(Text2, Chars2, _),
+          once(append(Chars1, Chars2, Chars3)),
+          chars_text(Type, Chars1, Text1)
+       ;  append(Chars1, Chars2, Chars3),
+          chars_text(Type, Chars1, Text1),
+          chars_text(Type, Chars2, Text2)
+       )
+    ).
+
+text_chars(Text, Chars, Type) :-
+    (  Text == [] ->
+       Chars = [],
+       Type = string
+    ;  atomic(Text) ->
+       atomic_chars(Text, Chars),
+       Type = atom
+    ;  must_be(list, Text),
+       maplist(must_be(character), Text),
+       Chars = Text,
+       Type = string
+    ).
+
+chars_text(atom, Chars, Atom) :-
+    atom_chars(Atom, Chars).
+chars_text(string, Chars, Chars).
+
+% sub_string(+String, ?Before, ?Length, ?After, ?SubString)
+%
+% Like sub_atom/5, but String and SubString are strings. All
+% decompositions of String are enumerated on backtracking.
+sub_string(String, Before, Length, After, SubString) :-
+    must_be(list, String),
+    can_be(integer, Before),
+    can_be(integer, Length),
+    can_be(integer, After),
+    (  integer(Before), Before < 0 ->
+       domain_error(not_less_than_zero, Before, sub_string/5)
+    ;  integer(Length), Length < 0 ->
+       domain_error(not_less_than_zero, Length, sub_string/5)
+    ;  integer(After), After < 0 ->
+       domain_error(not_less_than_zero, After, sub_string/5)
+    ;  append(BeforeChars, LengthAndAfterChars, String),
+       append(LengthChars, AfterChars, LengthAndAfterChars),
+       '$skip_max_list'(Before, -1, BeforeChars, []),
+       '$skip_max_list'(Length, -1, LengthChars, []),
+       '$skip_max_list'(After, -1, AfterChars, []),
+       SubString = LengthChars
+    ).
+
+% upcase_atom(+Atom, -UpperCase)
+%
+% UpperCase is Atom with every character mapped to upper case. The
+% full Unicode mapping is used, so the result may be longer than Atom.
+upcase_atom(Atom, UpperCase) :-
+    must_be(atom, Atom),
+    '$upcase_atom'(Atom, UpperCase).
+
+% downcase_atom(+Atom, -LowerCase)
+%
+% LowerCase is Atom with every character mapped to lower case.
+downcase_atom(Atom, LowerCase) :-
+    must_be(atom, Atom),
+    '$downcase_atom'(Atom, LowerCase).
+
+% term_string(?Term, ?String)
+%
+% If String is instantiated, it is parsed and the result unified with
+% Term. Otherwise String is Term written as by writeq/1.
+term_string(Term, String) :-
+    (  var(String) ->
+       write_term_to_chars(Term, [quoted(true)], String)
+    ;  must_be(list, String),
+       chars_to_term_(String, Term, _)
+    ).
+
 % Encodes Ch character to list of Bytes.
 char_utf8bytes(Ch, Bytes) :-
   char_code(Ch, Code),
@@ -199,6 +597,41 @@ read_line_to_chars(Stream, Cs0, Cs) :-
             )
         ).
 
+%% read_number(+Stream, -Number)
+%
+% Reads the longest prefix of Stream that is an unsigned number, be it
+% an integer, a float or a 0x, 0o, 0b or 0' literal, leaving the
+% stream positioned just after it. Fails, reading nothing, if Stream
+% doesn't start with a digit.
+
+read_number(Stream, Number) :-
+        '$read_number'(Stream, Number).
+
+%% read_string(+Stream, +Length, -Chars)
+%
+% Reads exactly Length characters from Stream into Chars, failing if
+% the stream ends first.
+
+read_string(Stream, Length, Chars) :-
+        must_be(integer, Length),
+        (   Length < 0 ->
+            domain_error(not_less_than_zero, Length, read_string/3)
+        ;   true
+        ),
+        '$get_n_chars'(Stream, Length, Chars0),
+        length(Chars0, Length),
+        Chars = Chars0.
+
+%% open_chars_stream(+Chars, -Stream)
+%
+% Opens an input stream reading the characters of Chars.
+
+open_chars_stream(Chars, Stream) :-
+        must_be(list, Chars),
+        maplist(must_be(character), Chars),
+        must_be(var, Stream),
+        '$open_input_string'(Chars, Stream).
+
 /* - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
    Relation between a list of characters Cs and its Base64 encoding Bs,
    also a list of characters.
@@ -91,10 +91,6 @@ domain_error(Expectation, Term) :-
 type_error(Expectation, Term) :-
         type_error(Expectation, Term, unknown(Term)-1).
 
-partition(Pred, Ls0, As, Bs) :-
-        include(Pred, Ls0, As),
-        exclude(Pred, Ls0, Bs).
-
 goal_expansion(get_attr(Var, Module, Value), (var(Var),get_atts(Var, Access))) :-
         Access =.. [Module,Value].
 
@@ -1411,10 +1407,6 @@ del_clpb(Var) :-
    them [] on backtracking, which is quite unfortunate in itself.
 - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
 
-b_setval(K, T) :- bb_b_put(K, T).
-nb_setval(K, T) :- bb_put(K, T).
-b_getval(K, T) :- bb_get(K, T).
-
 prepare_global_variables(BDD) :-
         clpb_next_id('$clpb_next_var', V0),
         clpb_next_id('$clpb_next_node', N0),
@@ -1665,30 +1657,6 @@ Compatibility predicates.
 - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
 
 
-include(Goal, List, Is) :-
-        include_(List, Goal, Is).
-
-include_([], _, []).
-include_([X1|Xs1], P, Is) :-
-        (   call(P, X1)
-        ->  Is = [X1|Is1]
-        ;   Is = Is1
-        ),
-        include_(Xs1, P, Is1).
-
-
-exclude(Goal, List, Is) :-
-        exclude_(List, Goal, Is).
-
-exclude_([], _, []).
-exclude_([X1|Xs1], P, Is) :-
-        (   call(P, X1)
-        ->  Is = Is1
-        ;   Is = [X1|Is1]
-        ),
-        exclude_(Xs1, P, Is1).
-
-
 partition(Pred, List, Less, Equal, Greater) :-
     partition_(List, Pred, Less, Equal, Greater).
 
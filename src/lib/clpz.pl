@@ -193,11 +193,6 @@ type_error(Expectation, Term) :-
         type_error(Expectation, Term, unknown(Term)-1).
 
 
-partition(Pred, Ls0, As, Bs) :-
-        include(Pred, Ls0, As),
-        exclude(Pred, Ls0, Bs).
-
-
 partition(Pred, Ls0, Ls, Es, Gs) :-
         partition_(Ls0, Pred, Ls, Es, Gs).
 
@@ -209,33 +204,6 @@ partition_([X|Xs], Pred, Ls0, Es0, Gs0) :-
         ;   Cmp = (>) -> Gs0 = [X|Rest], partition_(Xs, Pred, Ls0, Es0, Rest)
         ).
 
-/* - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
-   include/3 and exclude/3
-- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
-
-include(Goal, Ls0, Ls) :-
-        include_(Ls0, Goal, Ls).
-
-include_([], _, []).
-include_([L|Ls0], Goal, Ls) :-
-        (   call(Goal, L) ->
-            Ls = [L|Rest]
-        ;   Ls = Rest
-        ),
-        include_(Ls0, Goal, Rest).
-
-
-
-exclude(Goal, Ls0, Ls) :-
-        exclude_(Ls0, Goal, Ls).
-
-exclude_([], _, []).
-exclude_([L|Ls0], Goal, Ls) :-
-        (   call(Goal, L) ->
-            Ls = Rest
-        ;   Ls = [L|Rest]
-        ),
-        exclude_(Ls0, Goal, Rest).
 
 
 %:- discontiguous clpz:goal_expansion/5.
@@ -5912,8 +5880,6 @@ difference_arcs([V|Vs], FL0) -->
         ;   difference_arcs(Vs, FL0)
         ).
 
-writeln(T) :- write(T), nl.
-
 :- meta_predicate must_succeed(0).
 
 must_succeed(G) :-
@@ -1,5 +1,6 @@
 :- module(dcgs,
           [op(1105, xfy, '|'),
+		   dcg_translate_rule/2,
 		   phrase/2,
 		   phrase/3,
                    seq//1,
@@ -82,6 +83,13 @@ phrase_(phrase(NonTerminal), S0, S) :-
 phrase_(M:phrase(NonTerminal), S0, S) :-
     !,
     phrase(M:NonTerminal, S0, S).
+phrase_(\+ A, S0, S) :-
+    \+ phrase(A, S0, _),
+    S0 = S.
+phrase_(M:(\+ A), S0, S) :-
+    !,
+    \+ phrase(M:A, S0, _),
+    S0 = S.
 phrase_([T|Ts], S0, S) :-
     append([T|Ts], S, S0).
 phrase_(_:[T|Ts], S0, S) :-
@@ -144,7 +152,7 @@ dcg_constr({_}). % 7.14.7
 dcg_constr(call(_)). % 7.14.8
 dcg_constr(phrase(_)). % 7.14.9
 dcg_constr(!). % 7.14.10
-%% dcg_constr(\+ _). % 7.14.11 - not (existence implementation dep.)
+dcg_constr(\+ _). % 7.14.11 - not (existence implementation dep.)
 dcg_constr((_->_)). % 7.14.12 - if-then (existence implementation dep.)
 
 % The principal functor of the first argument indicates
@@ -176,6 +184,16 @@ dcg_cbody(( GRIf -> GRThen ), S0, S, ( If -> Then )) :-
     dcg_body(GRIf, S0, S1, If),
     dcg_body(GRThen, S1, S, Then).
 
+% dcg_translate_rule(+Rule, -Clause)
+%
+% Clause is the ordinary clause that the grammar rule Rule expands to
+% when loaded.
+dcg_translate_rule(Rule, Clause) :-
+    (  var(Rule) ->
+       throw(error(instantiation_error, dcg_translate_rule/2))
+    ;  dcg_rule(Rule, Clause)
+    ).
+
 user:term_expansion(Term0, Term) :-
     nonvar(Term0),
     dcg_rule(Term0, (Head :- Body)),
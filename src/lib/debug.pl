@@ -6,14 +6,17 @@
     op(950, fy, *),
     (*)/1,
     ($)/1,
-    ($-)/1
+    ($-)/1,
+    assertion/1
 ]).
 
 :- use_module(library(format), [portray_clause/1]).
+:- use_module(library(loader), [strip_module/3]).
 
 :- meta_predicate *(0).
 :- meta_predicate $(0).
 :- meta_predicate $-(0).
+:- meta_predicate assertion(0).
 
 $-(G_0) :-
    catch(G_0, Ex, ( portray_clause(exception:Ex:G_0), throw(Ex) ) ).
@@ -24,3 +27,14 @@ $(G_0) :-
    portray_clause(exit:G_0).
 
 *(_).
+
+% assertion(+G_0)
+%
+% Succeeds once, keeping the bindings of G_0, if G_0 succeeds.
+% Throws assertion_failed(G_0), less its module qualifier, if it fails.
+assertion(G_0) :-
+   (  call(G_0) ->
+      true
+   ;  strip_module(G_0, _, G),
+      throw(assertion_failed(G))
+   ).
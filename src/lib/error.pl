@@ -27,8 +27,15 @@
 
        - integer
        - atom
+       - callable
+       - character
        - list
        - boolean
+       - positive_integer (an integer > 0)
+       - nonneg (an integer >= 0)
+
+   For positive_integer and nonneg, a term that isn't an integer
+   yields a type error for integer.
 - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
 
 must_be(Type, Term) :-
@@ -44,10 +51,21 @@ must_be_(var, Term) :-
         ).
 must_be_(integer, Term) :- check_(integer, integer, Term).
 must_be_(atom, Term)    :- check_(atom, atom, Term).
+must_be_(callable, T)   :- check_(callable, callable, T).
 must_be_(character, T)  :- check_(error:character, character, T).
 must_be_(list, Term)    :- check_(error:ilist, list, Term).
 must_be_(type, Term)    :- check_(error:type, type, Term).
 must_be_(boolean, Term) :- check_(error:boolean, boolean, Term).
+must_be_(positive_integer, Term) :-
+        check_(integer, integer, Term),
+        (   Term > 0 -> true
+        ;   type_error(positive_integer, Term, must_be/2)
+        ).
+must_be_(nonneg, Term) :-
+        check_(integer, integer, Term),
+        (   Term >= 0 -> true
+        ;   type_error(nonneg, Term, must_be/2)
+        ).
 
 check_(Pred, Type, Term) :-
         (   var(Term) -> instantiation_error(must_be/2)
@@ -68,10 +86,13 @@ ilist([_|Ls]) :- ilist(Ls).
 type(type).
 type(integer).
 type(atom).
+type(callable).
 type(character).
 type(list).
 type(var).
 type(boolean).
+type(positive_integer).
+type(nonneg).
 
 /* - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
    can_be(Type, Term)
@@ -96,9 +117,12 @@ can_be(Type, Term) :-
 
 can_(integer, Term) :- integer(Term).
 can_(atom, Term)    :- atom(Term).
+can_(callable, T)   :- callable(T).
 can_(character, T)  :- character(T).
 can_(list, Term)    :- list_or_partial_list(Term).
 can_(boolean, Term) :- boolean(Term).
+can_(positive_integer, Term) :- integer(Term), Term > 0.
+can_(nonneg, Term)  :- integer(Term), Term >= 0.
 
 list_or_partial_list(Var) :- var(Var).
 list_or_partial_list([]).
@@ -18,6 +18,7 @@
 
      ~w    use the next available argument from Arguments here
      ~q    use the next argument here, formatted as by writeq/1
+     ~p    use the next argument here, formatted as by print/1
      ~a    use the next argument here, which must be an atom
      ~s    use the next argument here, which must be a string
      ~d    use the next argument here, which must be an integer
@@ -74,22 +75,33 @@
                    format/3,
                    portray_clause/1,
                    portray_clause/2,
-                   listing/1
+                   listing/1,
+                   print_message/2
                   ]).
 
 :- use_module(library(dcgs)).
 :- use_module(library(lists)).
 :- use_module(library(error)).
 :- use_module(library(charsio)).
+:- use_module(library(iso_ext), [with_output_to/2]).
 :- use_module(library(between)).
 
-format_(Fs, Args) -->
-        { must_be(list, Fs),
+format_(Fs0, Args) -->
+        { format_string_chars(Fs0, Fs),
           must_be(list, Args),
           unique_variable_names(Args, VNs),
           phrase(cells(Fs,Args,0,[],VNs), Cells) },
         format_cells(Cells).
 
+format_string_chars(Fs0, Fs) :-
+        (   atom(Fs0) -> atom_chars(Fs0, Fs)
+        ;   must_be(list, Fs0),
+            Fs0 = [F|_],
+            integer(F) ->
+            maplist(char_code, Fs, Fs0)
+        ;   Fs = Fs0
+        ).
+
 format_cells([]) --> [].
 format_cells([Cell|Cells]) -->
         format_cell(Cell),
@@ -169,6 +181,11 @@ cells([~,w|Fs], [Arg|Args], Tab, Es, VNs) --> !,
 cells([~,q|Fs], [Arg|Args], Tab, Es, VNs) --> !,
         { write_term_to_chars(Arg, [quoted(true),variable_names(VNs)], Chars) },
         cells(Fs, Args, Tab, [chars(Chars)|Es], VNs).
+cells([~,p|Fs], [Arg|Args], Tab, Es, VNs) --> !,
+        { (   with_output_to(chars(Chars), builtins:portray_hook(Arg)) -> true
+          ;   write_term_to_chars(Arg, [quoted(true),numbervars(true),variable_names(VNs)], Chars)
+          ) },
+        cells(Fs, Args, Tab, [chars(Chars)|Es], VNs).
 cells([~,a|Fs], [Arg|Args], Tab, Es, VNs) --> !,
         { atom_chars(Arg, Chars) },
         cells(Fs, Args, Tab, [chars(Chars)|Es], VNs).
@@ -385,6 +402,71 @@ format(Stream, Fs, Args) :-
         '$put_chars'(Stream, Cs),
         flush_output(Stream).
 
+/* - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
+   print_message(Kind, Message) translates Message to a list of lines
+   and prints them on user_error, each line prefixed according to
+   Kind (error, warning, informational, silent, ...).
+
+   Programs can take over both steps by defining, in module user:
+
+     message(Message)//   describes the lines of Message. The lines
+                          are elements Format-Args, Format (with no
+                          arguments) and nl.
+
+     message_hook(Message, Kind, Lines)
+                          called with the translated Lines. If it
+                          succeeds, nothing else is printed.
+
+   Messages without a translation are printed as by writeq/1, except
+   for format(Format, Args), which is printed as by format/2.
+- - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - */
+
+print_message(Kind, Message) :-
+        must_be(atom, Kind),
+        message_lines(Message, Lines),
+        (   user_hook(message_hook(_, _, _)),
+            user:message_hook(Message, Kind, Lines) ->
+            true
+        ;   Kind == silent ->
+            true
+        ;   message_prefix(Kind, Prefix),
+            phrase(message_chars(Lines, Prefix), Cs),
+            format(user_error, "~s", [Cs])
+        ).
+
+user_hook(Head) :-
+        \+ '$no_such_predicate'(user, Head).
+
+message_lines(Message, Lines) :-
+        (   user_hook(message(_, _, _)),
+            phrase(user:message(Message), Lines0) ->
+            Lines = Lines0
+        ;   Message = format(Fs, Args) ->
+            Lines = [Fs-Args]
+        ;   Lines = ["~q"-[Message]]
+        ).
+
+message_prefix(error, "ERROR: ") :- !.
+message_prefix(warning, "Warning: ") :- !.
+message_prefix(informational, "% ") :- !.
+message_prefix(_, "").
+
+% every line of a multi-line message carries the prefix, so that
+% continuation lines stay aligned with the first.
+message_chars(Lines, Prefix) -->
+        seq(Prefix),
+        message_lines_(Lines, Prefix),
+        "\n".
+
+message_lines_([], _) --> [].
+message_lines_([Line|Lines], Prefix) -->
+        message_line(Line, Prefix),
+        message_lines_(Lines, Prefix).
+
+message_line(nl, Prefix) --> !, "\n", seq(Prefix).
+message_line(Fs-Args, _) --> !, format_(Fs, Args).
+message_line(Fs, _) --> format_(Fs, []).
+
 /* - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - - -
 ?- phrase(format:cells("hello", [], 0, [], []), Cs).
 
@@ -588,8 +670,8 @@ listing(PI) :-
         ;   type_error(predicate_indicator, PI, listing/1)
         ),
         functor(Head, Name, Arity),
-        \+ \+ clause(Head, _), % only true if there is at least one clause
-        (   clause(Head, Body),
+        \+ \+ clause(user:Head, _), % only true if there is at least one clause
+        (   clause(user:Head, Body),
             (   Body == true ->
                 portray_clause(Head)
             ;   portray_clause((Head :- Body))
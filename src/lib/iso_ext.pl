@@ -3,24 +3,37 @@
 
 %% ?- use_module(library(iso_ext)).
 
-:- module(iso_ext, [bb_b_put/2,
+:- module(iso_ext, [(=@=)/2,
+                    (\=@=)/2,
+                    apply/2,
+                    b_getval/2,
+                    b_setval/2,
+                    bb_b_put/2,
                     bb_get/2,
                     bb_put/2,
                     call_cleanup/2,
+                    call_with_depth_limit/3,
                     call_with_inference_limit/3,
                     forall/2,
+                    ignore/1,
+                    nb_getval/2,
+                    nb_setval/2,
                     partial_string/1,
                     partial_string/3,
                     partial_string_tail/2,
                     setup_call_cleanup/3,
+                    statistics/2,
                     call_nth/2,
                     variant/2,
-                    copy_term_nat/2]).
+                    copy_term_nat/2,
+                    with_output_to/2]).
 
 :- use_module(library(error), [can_be/2,
                                domain_error/3,
                                instantiation_error/1,
+                               must_be/2,
                                type_error/3]).
+:- use_module(library(lists), [append/3]).
 
 
 :- meta_predicate call_cleanup(0, 0).
@@ -30,7 +43,39 @@
 :- meta_predicate forall(0, 0).
 
 forall(Generate, Test) :-
-    \+ (Generate, \+ Test).
+    call(Generate),
+    \+ call(Test),
+    !,
+    false.
+forall(_, _).
+
+:- meta_predicate ignore(0).
+
+ignore(G) :- call(G), !.
+ignore(_).
+
+:- meta_predicate apply(0, ?).
+
+apply(G, ExtraArgs) :-
+    must_be(list, ExtraArgs),
+    (  var(G) ->
+       instantiation_error(apply/2)
+    ;  G = M:G0 ->
+       apply_goal(G0, ExtraArgs, G1),
+       call(M:G1)
+    ;  apply_goal(G, ExtraArgs, G1),
+       call(G1)
+    ).
+
+apply_goal(G0, ExtraArgs, G) :-
+    (  var(G0) ->
+       instantiation_error(apply/2)
+    ;  callable(G0) ->
+       G0 =.. Ts0,
+       append(Ts0, ExtraArgs, Ts),
+       G =.. Ts
+    ;  type_error(callable, G0, apply/2)
+    ).
 
 %% (non-)backtrackable global variables.
 
@@ -54,6 +99,48 @@ bb_get(Key, Value) :-
     ;  type_error(atom, Key, bb_get/2)
     ).
 
+%% nb_setval(+Key, +Value)
+%
+% Like bb_put/2, except that an unbound Key is an instantiation error.
+% The value is copied off the heap, so it survives backtracking.
+
+nb_setval(Key, Value) :-
+    must_be(atom, Key),
+    '$store_global_var'(Key, Value).
+
+%% nb_getval(+Key, ?Value)
+%
+% Unifies Value with the value last stored under Key. Unlike bb_get/2,
+% throws an existence error if nothing is stored under Key.
+
+nb_getval(Key, Value) :-
+    must_be(atom, Key),
+    (  '$fetch_global_var'(Key, Value0) ->
+       Value = Value0
+    ;  throw(error(existence_error(variable, Key), nb_getval/2))
+    ).
+
+%% b_setval(+Key, +Value)
+%
+% Like bb_b_put/2: the assignment is undone on backtracking, restoring
+% the value Key had before, or leaving it unset if it had none.
+
+b_setval(Key, Value) :-
+    must_be(atom, Key),
+    '$store_backtrackable_global_var'(Key, Value).
+
+%% b_getval(+Key, ?Value)
+%
+% Unifies Value with the current value of Key, throwing an existence
+% error if Key has none.
+
+b_getval(Key, Value) :-
+    must_be(atom, Key),
+    (  '$fetch_global_var'(Key, Value0) ->
+       Value = Value0
+    ;  throw(error(existence_error(variable, Key), b_getval/2))
+    ).
+
 
 call_cleanup(G, C) :- setup_call_cleanup(true, G, C).
 
@@ -160,8 +247,48 @@ call_with_inference_limit(_, _, R, Bb, B) :-
     '$erase_ball',
     '$call_with_default_policy'(handle_ile(B, Ball, R)).
 
+% call_with_depth_limit
+
+% the depth of a call is measured in inferences, using the same counter
+% as call_with_inference_limit/3. on success, R is the number of
+% inferences the solution took.
+
+:- meta_predicate call_with_depth_limit(0, ?, ?).
+
+call_with_depth_limit(G, L, R) :-
+    '$get_current_block'(Bb),
+    '$get_b_value'(B),
+    '$call_with_default_policy'(call_with_depth_limit(G, L, R, Bb, B)),
+    '$remove_call_policy_check'(B).
+
+:- non_counted_backtracking call_with_depth_limit/5.
+call_with_depth_limit(G, L, R, Bb, B) :-
+    '$install_new_block'(NBb),
+    '$install_inference_counter'(B, L, Count0),
+    call(G),
+    '$remove_inference_counter'(B, Count1),
+    '$call_with_default_policy'(is(R, Count1 - Count0)),
+    '$call_with_default_policy'(is(Diff, L - R)),
+    '$call_with_default_policy'(end_block(B, Bb, NBb, Diff)).
+call_with_depth_limit(_, _, R, Bb, B) :-
+    '$reset_block'(Bb),
+    '$remove_inference_counter'(B, _),
+    (  '$get_ball'(Ball),
+       '$get_level'(Cp),
+       '$set_cp_by_default'(Cp)
+    ;  '$remove_call_policy_check'(B),
+       '$fail'
+    ),
+    '$erase_ball',
+    '$call_with_default_policy'(handle_ile(B, Ball, _)),
+    R = depth_limit_exceeded.
+
 variant(X, Y) :- '$variant'(X, Y).
 
+X =@= Y :- '$variant'(X, Y).
+
+X \=@= Y :- \+ '$variant'(X, Y).
+
 partial_string(String, L, L0) :-
     (  String == [] ->
        L = L0
@@ -215,3 +342,91 @@ call_nth_nesting(ID) :-
 
 copy_term_nat(Source, Dest) :-
     '$copy_term_without_attr_vars'(Source, Dest).
+
+
+%% with_output_to(+Sink, :Goal)
+%
+% Runs Goal once with the current output redirected to a string,
+% then unifies the captured text with Sink, one of atom(A),
+% string(S), chars(Cs) or codes(Cs). The previous current output is
+% restored however Goal exits.
+
+:- meta_predicate with_output_to(?, 0).
+
+with_output_to(Sink, Goal) :-
+    (  var(Sink) ->
+       instantiation_error(with_output_to/2)
+    ;  output_sink(Sink) ->
+       true
+    ;  domain_error(output_sink, Sink, with_output_to/2)
+    ),
+    current_output(Stream0),
+    '$open_output_string'(Stream),
+    set_output(Stream),
+    (  catch(Goal, Exception, true) ->
+       Succeeded = true
+    ;  Succeeded = false
+    ),
+    set_output(Stream0),
+    '$close_output_string'(Stream, Chars),
+    (  nonvar(Exception) ->
+       throw(Exception)
+    ;  Succeeded == true,
+       output_sink_chars(Sink, Chars)
+    ).
+
+output_sink(atom(_)).
+output_sink(string(_)).
+output_sink(chars(_)).
+output_sink(codes(_)).
+
+output_sink_chars(atom(Atom), Chars) :-
+    atom_chars(Atom, Chars).
+output_sink_chars(string(Chars), Chars).
+output_sink_chars(chars(Chars), Chars).
+output_sink_chars(codes(Codes), Chars) :-
+    atom_chars(Atom, Chars),
+    atom_codes(Atom, Codes).
+
+% statistics/2. runtime is CPU time in milliseconds, as a list of the
+% total and the time since runtime was last read. atoms is a list of
+% the number of atoms in the atom table and the number of hits and
+% misses of atom lookups in it, which are only counted if Scryer was
+% built with the atom_stats feature. global_stack, local_stack and
+% trail are lists of the used and free cells of the heap, the stack
+% of environments and choice points, and the trail.
+
+statistics(Key, Value) :-
+    (  var(Key) ->
+       instantiation_error(statistics/2)
+    ;  statistics_key(Key) ->
+       statistics_(Key, Value)
+    ;  domain_error(statistics_key, Key, statistics/2)
+    ).
+
+statistics_key(atoms).
+statistics_key(global_stack).
+statistics_key(inferences).
+statistics_key(local_stack).
+statistics_key(runtime).
+statistics_key(trail).
+
+statistics_(atoms, [Count, Hits, Misses]) :-
+    '$atom_table_statistics'(Count, Hits, Misses).
+statistics_(global_stack, [Used, Free]) :-
+    '$memory_statistics'(global_stack, Used, Free).
+statistics_(inferences, N) :-
+    '$inference_count'(N).
+statistics_(local_stack, [Used, Free]) :-
+    '$memory_statistics'(local_stack, Used, Free).
+statistics_(runtime, [Total, SinceLast]) :-
+    '$cpu_now'(Seconds),
+    Total is truncate(Seconds * 1000),
+    (  bb_get('$last_runtime', Last) ->
+       true
+    ;  Last = 0
+    ),
+    bb_put('$last_runtime', Total),
+    SinceLast is Total - Last.
+statistics_(trail, [Used, Free]) :-
+    '$memory_statistics'(trail, Used, Free).
@@ -1,8 +1,11 @@
-:- module(lists, [member/2, select/3, append/2, append/3, foldl/4, foldl/5,
-		          memberchk/2, reverse/2, length/2, maplist/2,
+:- module(lists, [member/2, select/3, selectchk/3, append/2, append/3, foldl/4, foldl/5,
+		          foldl/6, memberchk/2, reverse/2, length/2, maplist/2,
 		          maplist/3, maplist/4, maplist/5, maplist/6,
 		          maplist/7, maplist/8, maplist/9, same_length/2, nth0/3,
-		          sum_list/2, transpose/2, list_to_set/2, list_max/2, list_min/2]).
+		          nth1/3, last/2, sum_list/2, transpose/2, list_to_set/2, list_max/2, list_min/2,
+		          max_list/2, min_list/2, max_member/2, min_member/2,
+		          msort/2, predsort/3, sort/4, permutation/2, include/3,
+		          exclude/3, partition/4]).
 
 
 :- use_module(library(error)).
@@ -19,6 +22,13 @@
 
 :- meta_predicate foldl(3, ?, ?, ?).
 :- meta_predicate foldl(4, ?, ?, ?, ?).
+:- meta_predicate foldl(5, ?, ?, ?, ?, ?).
+
+:- meta_predicate predsort(3, ?, ?).
+
+:- meta_predicate include(1, ?, ?).
+:- meta_predicate exclude(1, ?, ?).
+:- meta_predicate partition(1, ?, ?, ?).
 
 
 length(Xs, N) :-
@@ -57,6 +67,8 @@ member(X, [_|Xs]) :- member(X, Xs).
 select(X, [X|Xs], Xs).
 select(X, [Y|Xs], [Y|Ys]) :- select(X, Xs, Ys).
 
+selectchk(X, Xs, Ys) :- select(X, Xs, Ys), !.
+
 
 append([], []).
 append([L0|Ls0], Ls) :-
@@ -125,6 +137,14 @@ maplist(Cont, [E1|E1s], [E2|E2s], [E3|E3s], [E4|E4s], [E5|E5s], [E6|E6s], [E7|E7
     maplist(Cont, E1s, E2s, E3s, E4s, E5s, E6s, E7s, E8s).
 
 
+last([L|Ls], Last) :-
+        last_(Ls, L, Last).
+
+last_([], Last, Last).
+last_([L|Ls], _, Last) :-
+        last_(Ls, L, Last).
+
+
 sum_list(Ls, S) :-
         foldl(lists:sum_, Ls, 0, S).
 
@@ -137,6 +157,57 @@ same_length([_|As], [_|Bs]) :-
         same_length(As, Bs).
 
 
+include(Goal, Ls0, Ls) :-
+        include_(Ls0, Goal, Ls).
+
+include_([], _, []).
+include_([L|Ls0], Goal, Ls) :-
+        (   call(Goal, L) ->
+            Ls = [L|Rest]
+        ;   Ls = Rest
+        ),
+        include_(Ls0, Goal, Rest).
+
+
+exclude(Goal, Ls0, Ls) :-
+        exclude_(Ls0, Goal, Ls).
+
+exclude_([], _, []).
+exclude_([L|Ls0], Goal, Ls) :-
+        (   call(Goal, L) ->
+            Ls = Rest
+        ;   Ls = [L|Rest]
+        ),
+        exclude_(Ls0, Goal, Rest).
+
+
+partition(Goal, Ls0, Is, Es) :-
+        partition_(Ls0, Goal, Is, Es).
+
+partition_([], _, [], []).
+partition_([L|Ls0], Goal, Is0, Es0) :-
+        (   call(Goal, L) ->
+            Is0 = [L|Is],
+            Es0 = Es
+        ;   Is0 = Is,
+            Es0 = [L|Es]
+        ),
+        partition_(Ls0, Goal, Is, Es).
+
+
+% fixing the length of both lists first keeps permutation/2 from
+% looking for ever longer lists once the permutations are exhausted.
+
+permutation(Xs, Ys) :-
+        same_length(Xs, Ys),
+        permutation_(Xs, Ys).
+
+permutation_([], []).
+permutation_(Xs, [Y|Ys]) :-
+        select(Y, Xs, Xs1),
+        permutation_(Xs1, Ys).
+
+
 foldl(Goal_3, Ls, A0, A) :-
         foldl_(Ls, Goal_3, A0, A).
 
@@ -155,6 +226,16 @@ foldl_([X|Xs], [Y|Ys], G_4, A0, A) :-
         call(G_4, X, Y, A0, A1),
         foldl_(Xs, Ys, G_4, A1, A).
 
+
+foldl(Goal_5, Xs, Ys, Zs, A0, A) :-
+        foldl_(Xs, Ys, Zs, Goal_5, A0, A).
+
+
+foldl_([], [], [], _, A, A).
+foldl_([X|Xs], [Y|Ys], [Z|Zs], G_5, A0, A) :-
+        call(G_5, X, Y, Z, A0, A1),
+        foldl_(Xs, Ys, Zs, G_5, A1, A).
+
 transpose(Ls, Ts) :-
         lists_transpose(Ls, Ts).
 
@@ -197,6 +278,125 @@ unify_same(E-V, Prev-Var, E-V) :-
         ).
 
 
+msort(Ls, Ss) :-
+        '$msort'(Ls, Ss).
+
+
+% sort(Key, Order, List, Sorted) sorts List on the Key-th argument of
+% each element, or on the whole element if Key is 0. keysort/2 is
+% stable, so elements with equal keys keep their relative order.
+
+sort(Key, Order, List, Sorted) :-
+        must_be(integer, Key),
+        (   Key < 0 ->
+            domain_error(not_less_than_zero, Key, sort/4)
+        ;   true
+        ),
+        must_be(atom, Order),
+        (   sort_order(Order) ->
+            true
+        ;   domain_error(order, Order, sort/4)
+        ),
+        must_be(list, List),
+        maplist(sort_key_pair(Key), List, Pairs0),
+        keysort(Pairs0, Pairs1),
+        sort_pairs(Order, Pairs1, Pairs),
+        pairs_values_(Pairs, Sorted).
+
+sort_order(@<).
+sort_order(@=<).
+sort_order(@>).
+sort_order(@>=).
+
+sort_key_pair(0, E, E-E) :- !.
+sort_key_pair(Key, E, K-E) :-
+        (   var(E) ->
+            instantiation_error(sort/4)
+        ;   compound(E) ->
+            arg(Key, E, K)
+        ;   type_error(compound, E, sort/4)
+        ).
+
+sort_pairs(@=<, Pairs, Pairs).
+sort_pairs(@<, Pairs0, Pairs) :-
+        unique_key_pairs(Pairs0, Pairs).
+sort_pairs(@>=, Pairs0, Pairs) :-
+        key_groups(Pairs0, Groups0),
+        reverse(Groups0, Groups),
+        append(Groups, Pairs).
+sort_pairs(@>, Pairs0, Pairs) :-
+        unique_key_pairs(Pairs0, Pairs1),
+        reverse(Pairs1, Pairs).
+
+unique_key_pairs([], []).
+unique_key_pairs([K-V|Pairs0], [K-V|Pairs]) :-
+        drop_key(Pairs0, K, Pairs1),
+        unique_key_pairs(Pairs1, Pairs).
+
+drop_key([], _, []).
+drop_key([K0-V|Pairs0], K, Pairs) :-
+        (   K0 == K ->
+            drop_key(Pairs0, K, Pairs)
+        ;   Pairs = [K0-V|Pairs0]
+        ).
+
+key_groups([], []).
+key_groups([K-V|Pairs0], [[K-V|Group]|Groups]) :-
+        same_key_prefix(Pairs0, K, Group, Pairs1),
+        key_groups(Pairs1, Groups).
+
+same_key_prefix([], _, [], []).
+same_key_prefix([K0-V|Pairs0], K, Group, Pairs) :-
+        (   K0 == K ->
+            Group = [K0-V|Group1],
+            same_key_prefix(Pairs0, K, Group1, Pairs)
+        ;   Group = [],
+            Pairs = [K0-V|Pairs0]
+        ).
+
+pairs_values_([], []).
+pairs_values_([_-V|Pairs], [V|Vs]) :-
+        pairs_values_(Pairs, Vs).
+
+
+predsort(P, L, Sorted) :-
+        length(L, N),
+        predsort(P, N, L, _, Sorted1),
+        !,
+        Sorted = Sorted1.
+
+predsort(P, 2, [X1,X2|L], L, R) :-
+        !,
+        call(P, Delta, X1, X2),
+        sort2(Delta, X1, X2, R).
+predsort(_, 1, [X|L], L, [X]) :- !.
+predsort(_, 0, L, L, []) :- !.
+predsort(P, N, L1, L3, R) :-
+        N1 is N // 2,
+        N2 is N - N1,
+        predsort(P, N1, L1, L2, R1),
+        predsort(P, N2, L2, L3, R2),
+        predmerge(P, R1, R2, R).
+
+sort2(<, X1, X2, [X1,X2]).
+sort2(=, X1, _,  [X1]).
+sort2(>, X1, X2, [X2,X1]).
+
+predmerge(_, [], R, R) :- !.
+predmerge(_, R, [], R) :- !.
+predmerge(P, [H1|T1], [H2|T2], Result) :-
+        call(P, Delta, H1, H2),
+        !,
+        predmerge_(Delta, P, H1, H2, T1, T2, Result).
+
+predmerge_(<, P, H1, H2, T1, T2, [H1|R]) :-
+        predmerge(P, T1, [H2|T2], R).
+predmerge_(=, P, H1, _, T1, T2, [H1|R]) :-
+        predmerge(P, T1, T2, R).
+predmerge_(>, P, H1, H2, T1, T2, [H2|R]) :-
+        predmerge(P, [H1|T1], T2, R).
+
+
 nth0(N, Es, E) :-
         can_be(integer, N),
         can_be(list, Es),
@@ -212,12 +412,22 @@ nth0_index(N, [_|Es], E) :-
         nth0_index(N1, Es, E).
 
 nth0_search(N, Es, E) :-
-        nth0_search(0, N, Es, E).
+        nth_search(0, N, Es, E).
 
-nth0_search(N, N, [E|_], E).
-nth0_search(N0, N, [_|Es], E) :-
+nth_search(N, N, [E|_], E).
+nth_search(N0, N, [_|Es], E) :-
         N1 is N0 + 1,
-        nth0_search(N1, N, Es, E).
+        nth_search(N1, N, Es, E).
+
+
+nth1(N, Es, E) :-
+        can_be(integer, N),
+        can_be(list, Es),
+        (   integer(N) ->
+            N0 is N - 1,
+            nth0_index(N0, Es, E)
+        ;   nth_search(1, N, Es, E)
+        ).
 
 
 list_max([N|Ns], Max) :-
@@ -231,3 +441,29 @@ list_min([N|Ns], Min) :-
 
 list_min_(N, Min0, Min) :-
     Min is min(N, Min0).
+
+
+max_list(Ns, Max) :-
+    list_max(Ns, Max).
+
+min_list(Ns, Min) :-
+    list_min(Ns, Min).
+
+
+max_member(Max, [E|Es]) :-
+    foldl(lists:max_member_, Es, E, Max).
+
+max_member_(E, Max0, Max) :-
+    (   E @> Max0 ->
+        Max = E
+    ;   Max = Max0
+    ).
+
+min_member(Min, [E|Es]) :-
+    foldl(lists:min_member_, Es, E, Min).
+
+min_member_(E, Min0, Min) :-
+    (   E @< Min0 ->
+        Min = E
+    ;   Min = Min0
+    ).
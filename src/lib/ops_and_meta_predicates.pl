@@ -46,6 +46,8 @@
 :- op(700, xfx, @>=).
 :- op(700, xfx, @<).
 :- op(700, xfx, @>).
+:- op(700, xfx, =@=).
+:- op(700, xfx, \=@=).
 
 % conditional operators.
 :- op(1050, xfy, ->).
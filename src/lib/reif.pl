@@ -1,6 +1,7 @@
 :- module(reif, [if_/3, (=)/3, (',')/3, (;)/3, cond_t/3, dif/3,
 		 memberd_t/3, tfilter/3, tmember/2, tmember_t/3,
-		 tpartition/4]).
+		 tpartition/4, (=:=)/3, (=\=)/3, (<)/3, (>)/3, (=<)/3,
+		 (>=)/3]).
 
 :- use_module(library(dif)).
 
@@ -28,6 +29,98 @@ dif(X, Y, T) :-
 non(true, false).
 non(false, true).
 
+% reified arithmetic comparison. both sides are evaluated, so unlike
+% =/3 they must be sufficiently instantiated.
+
+=:=(X, Y, T) :-
+    (  X =:= Y -> T = true
+    ;  T = false
+    ).
+
+=\=(X, Y, T) :-
+    (  X =\= Y -> T = true
+    ;  T = false
+    ).
+
+<(X, Y, T) :-
+    (  X < Y -> T = true
+    ;  T = false
+    ).
+
+>(X, Y, T) :-
+    (  X > Y -> T = true
+    ;  T = false
+    ).
+
+=<(X, Y, T) :-
+    (  X =< Y -> T = true
+    ;  T = false
+    ).
+
+>=(X, Y, T) :-
+    (  X >= Y -> T = true
+    ;  T = false
+    ).
+
+% calls of the reified comparisons, and if_/3 goals whose condition is
+% one of them, are compiled to the arithmetic comparison they reify
+% rather than to a call of the predicates above. goals are only
+% expanded where they resolve to this module's predicates, so that
+% predicates of the same name defined elsewhere are left alone.
+
+user:goal_expansion(Goal0, Goal) :-
+    nonvar(Goal0),
+    (  Goal0 = reif:Goal1 ->
+       Module = reif
+    ;  Goal1 = Goal0,
+       (  prolog_load_context(module, Module) ->
+          true
+       ;  Module = user
+       )
+    ),
+    reified_comparison_expansion(Goal1, Module, Goal).
+
+reified_comparison_expansion(Goal0, Module, (Comparison -> T = true ; T = false)) :-
+    Goal0 =.. [Op, X, Y, T],
+    arithmetic_comparison(Op),
+    reif_predicate(Module, Op, 3),
+    Comparison =.. [Op, X, Y].
+reified_comparison_expansion(if_(If_1, Then_0, Else_0), Module, (If_1 -> Then ; Else)) :-
+    nonvar(If_1),
+    functor(If_1, Op, 2),
+    arithmetic_comparison(Op),
+    reif_predicate(Module, if_, 3),
+    reif_predicate(Module, Op, 3),
+    cut_opaque(Then_0, Then),
+    cut_opaque(Else_0, Else).
+
+reif_predicate(Module, Name, Arity) :-
+    '$is_imported_predicate'(Module, Name, Arity, reif).
+
+arithmetic_comparison(=:=).
+arithmetic_comparison(=\=).
+arithmetic_comparison(<).
+arithmetic_comparison(>).
+arithmetic_comparison(=<).
+arithmetic_comparison(>=).
+
+% if_/3 calls its branches, so a cut in them mustn't reach the clause
+% they are inlined into.
+
+cut_opaque(Goal0, Goal) :-
+    (  contains_cut(Goal0) ->
+       Goal = call(Goal0)
+    ;  Goal = Goal0
+    ).
+
+contains_cut(Goal) :-
+    nonvar(Goal),
+    (  Goal == ! -> true
+    ;  Goal = (G1, G2) -> ( contains_cut(G1) ; contains_cut(G2) )
+    ;  Goal = (G1 ; G2) -> ( contains_cut(G1) ; contains_cut(G2) )
+    ;  Goal = (G1 -> G2) -> ( contains_cut(G1) ; contains_cut(G2) )
+    ).
+
 :- meta_predicate tfilter(1, ?, ?).
 
 tfilter(C_2, Es, Fs) :-
@@ -1,4 +1,4 @@
-:- module(terms, [numbervars/3]).
+:- module(terms, [numbervars/3, term_hash/2]).
 
 :- use_module(library(error)).
 
@@ -17,3 +17,15 @@ numberlist([], N, N).
 numberlist(['$VAR'(N0)|Vars], N0, N) :-
    N1 is N0+1,
    numberlist(Vars, N1, N).
+
+% term_hash(+Term, ?Hash)
+%
+% Hash is a non-negative integer computed from the structure of Term,
+% the same for equal terms across runs. Fails if Term isn't ground.
+term_hash(Term, Hash) :-
+   can_be(integer, Hash),
+   ground(Term),
+   (  acyclic_term(Term) ->
+      '$term_hash'(Term, Hash)
+   ;  type_error(acyclic_term, Term, term_hash/2)
+   ).
@@ -0,0 +1,149 @@
+:- module(when, [when/2]).
+
+:- use_module(library(atts)).
+:- use_module(library(dcgs)).
+:- use_module(library(error), [domain_error/3, instantiation_error/1]).
+:- use_module(library(lists), [append/3, member/2]).
+
+:- attribute suspended/1.
+
+:- meta_predicate when(+, 0).
+
+%% when(+Condition, :Goal)
+%
+% Delays Goal until Condition holds, and runs it then. Condition is
+% one of nonvar(X), ground(X) and ?=(X, Y), or a conjunction
+% (C1, C2) or disjunction (C1 ; C2) of conditions. Goal runs at most
+% once even if several disjuncts hold.
+
+when(Condition, Goal) :-
+    must_be_condition(Condition),
+    trigger(Condition, Goal).
+
+must_be_condition(Condition) :-
+    (   var(Condition) ->
+        instantiation_error(when/2)
+    ;   condition(Condition) ->
+        true
+    ;   domain_error(when_condition, Condition, when/2)
+    ).
+
+condition(nonvar(_)).
+condition(ground(_)).
+condition(?=(_, _)).
+condition((C1, C2)) :-
+    must_be_condition(C1),
+    must_be_condition(C2).
+condition((C1 ; C2)) :-
+    must_be_condition(C1),
+    must_be_condition(C2).
+
+% each trigger either runs its goal or suspends itself on a variable
+% whose binding might make the condition hold, to be tried again.
+
+trigger(nonvar(X), Goal) :-
+    (   nonvar(X) ->
+        call(Goal)
+    ;   suspend(X, trigger(nonvar(X), Goal))
+    ).
+trigger(ground(X), Goal) :-
+    term_variables(X, Vs),
+    (   Vs = [V|_] ->
+        suspend(V, trigger(ground(X), Goal))
+    ;   call(Goal)
+    ).
+trigger(?=(X, Y), Goal) :-
+    trigger_determined(X, Y, _, Goal).
+trigger((C1, C2), Goal) :-
+    trigger(C1, trigger(C2, Goal)).
+trigger((C1 ; C2), Goal) :-
+    trigger(C1, call_once(Done, (C1 ; C2), Goal)),
+    trigger(C2, call_once(Done, (C1 ; C2), Goal)).
+
+trigger_determined(X, Y, Done, Goal) :-
+    (   nonvar(Done) ->
+        true
+    ;   determined(X, Y) ->
+        Done = true,
+        call(Goal)
+    ;   term_variables(X-Y, Vs),
+        suspend_once(Vs, trigger_determined(X, Y, Done, Goal))
+    ).
+
+% X and Y are compared as copies without attributes, since unifying
+% them would wake the goals suspended on them.
+
+determined(X, Y) :-
+    copy_term(X-Y, X1-Y1, _),
+    (   X1 == Y1 ->
+        true
+    ;   \+ X1 = Y1
+    ).
+
+call_once(Done, _, Goal) :-
+    (   nonvar(Done) ->
+        true
+    ;   Done = true,
+        call(Goal)
+    ).
+
+suspend(Var, Goal) :-
+    (   get_atts(Var, suspended(Goals0)) ->
+        append(Goals0, [when:Goal], Goals),
+        put_atts(Var, suspended(Goals))
+    ;   put_atts(Var, suspended([when:Goal]))
+    ).
+
+% suspends Goal on those of Vars that don't already carry it.
+
+suspend_once([], _).
+suspend_once([Var|Vars], Goal) :-
+    (   get_atts(Var, suspended(Goals)),
+        member(Goal0, Goals),
+        Goal0 == when:Goal ->
+        true
+    ;   suspend(Var, Goal)
+    ),
+    suspend_once(Vars, Goal).
+
+% binding a variable with suspended triggers tries them all again,
+% including when it is bound to another variable, which the
+% triggers then suspend themselves on.
+
+verify_attributes(Var, _, Goals) :-
+    (   get_atts(Var, suspended(Goals)) ->
+        true
+    ;   Goals = []
+    ).
+
+attribute_goals(Var) -->
+    { get_atts(Var, suspended(Goals)),
+      put_atts(Var, -suspended(_)) },
+    residual_goals(Goals).
+
+residual_goals([]) --> [].
+residual_goals([Goal0|Goals]) -->
+    (   { residual_goal(Goal0, Goal) } ->
+        [Goal]
+    ;   []
+    ),
+    residual_goals(Goals).
+
+% the triggers are reported as the when/2 goals that would suspend
+% them again. triggers whose goal has already run are left out.
+
+residual_goal(when:trigger(Condition0, Goal0), when(Condition, Goal)) :-
+    residual_when(Condition0, Goal0, Condition, Goal).
+residual_goal(when:trigger_determined(X, Y, Done, Goal0), when(Condition, Goal)) :-
+    var(Done),
+    residual_when(?=(X, Y), Goal0, Condition, Goal).
+
+residual_when(Condition0, Goal0, Condition, Goal) :-
+    (   Goal0 = trigger(C, Goal1) ->
+        residual_when((Condition0, C), Goal1, Condition, Goal)
+    ;   Goal0 = call_once(Done, Disjunction, Goal1) ->
+        var(Done),
+        residual_when(Disjunction, Goal1, Condition, Goal)
+    ;   Condition = Condition0,
+        Goal = Goal0
+    ).
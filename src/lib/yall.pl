@@ -0,0 +1,98 @@
+:- module(yall, [(>>)/2, (>>)/3, (>>)/4, (>>)/5, (>>)/6, (>>)/7,
+                 (>>)/8, (>>)/9, (/)/2, (/)/3, (/)/4, (/)/5, (/)/6,
+                 (/)/7, (/)/8, (/)/9]).
+
+/*  Lambda expressions in the style of SWI-Prolog's library(yall).
+
+    Params>>Lambda calls Lambda after unifying the list Params with
+    the extra arguments of the call. Arguments beyond the length of
+    Params are passed on to Lambda. Free/Lambda marks the variables of
+    the term Free as global: they are shared with the context of the
+    lambda, whereas all other variables of the lambda are renamed on
+    each call.
+*/
+
+:- use_module(library(iso_ext)).
+
+:- meta_predicate '>>'(?, 0).
+:- meta_predicate '>>'(?, 1, ?).
+:- meta_predicate '>>'(?, 2, ?, ?).
+:- meta_predicate '>>'(?, 3, ?, ?, ?).
+:- meta_predicate '>>'(?, 4, ?, ?, ?, ?).
+:- meta_predicate '>>'(?, 5, ?, ?, ?, ?, ?).
+:- meta_predicate '>>'(?, 6, ?, ?, ?, ?, ?, ?).
+:- meta_predicate '>>'(?, 7, ?, ?, ?, ?, ?, ?, ?).
+
+:- meta_predicate '/'(?, 0).
+:- meta_predicate '/'(?, 1, ?).
+:- meta_predicate '/'(?, 2, ?, ?).
+:- meta_predicate '/'(?, 3, ?, ?, ?).
+:- meta_predicate '/'(?, 4, ?, ?, ?, ?).
+:- meta_predicate '/'(?, 5, ?, ?, ?, ?, ?).
+:- meta_predicate '/'(?, 6, ?, ?, ?, ?, ?, ?).
+:- meta_predicate '/'(?, 7, ?, ?, ?, ?, ?, ?, ?).
+
+'>>'(Ps, L) :-
+    lambda(Ps, L, []).
+'>>'(Ps, L, A1) :-
+    lambda(Ps, L, [A1]).
+'>>'(Ps, L, A1, A2) :-
+    lambda(Ps, L, [A1, A2]).
+'>>'(Ps, L, A1, A2, A3) :-
+    lambda(Ps, L, [A1, A2, A3]).
+'>>'(Ps, L, A1, A2, A3, A4) :-
+    lambda(Ps, L, [A1, A2, A3, A4]).
+'>>'(Ps, L, A1, A2, A3, A4, A5) :-
+    lambda(Ps, L, [A1, A2, A3, A4, A5]).
+'>>'(Ps, L, A1, A2, A3, A4, A5, A6) :-
+    lambda(Ps, L, [A1, A2, A3, A4, A5, A6]).
+'>>'(Ps, L, A1, A2, A3, A4, A5, A6, A7) :-
+    lambda(Ps, L, [A1, A2, A3, A4, A5, A6, A7]).
+
+'/'(Free, L) :-
+    free_lambda(Free, L, []).
+'/'(Free, L, A1) :-
+    free_lambda(Free, L, [A1]).
+'/'(Free, L, A1, A2) :-
+    free_lambda(Free, L, [A1, A2]).
+'/'(Free, L, A1, A2, A3) :-
+    free_lambda(Free, L, [A1, A2, A3]).
+'/'(Free, L, A1, A2, A3, A4) :-
+    free_lambda(Free, L, [A1, A2, A3, A4]).
+'/'(Free, L, A1, A2, A3, A4, A5) :-
+    free_lambda(Free, L, [A1, A2, A3, A4, A5]).
+'/'(Free, L, A1, A2, A3, A4, A5, A6) :-
+    free_lambda(Free, L, [A1, A2, A3, A4, A5, A6]).
+'/'(Free, L, A1, A2, A3, A4, A5, A6, A7) :-
+    free_lambda(Free, L, [A1, A2, A3, A4, A5, A6, A7]).
+
+% Free/Params>>Lambda is read as (Free/Params)>>Lambda. the variables
+% of Free are shared with the context, so only the rest of the lambda
+% is copied.
+lambda(Free/Ps0, L0, As) :-
+    !,
+    copy_term_nat(Free/Ps0-L0, Free/Ps-L),
+    bind_params(Ps, As, L).
+lambda(Ps0, L0, As) :-
+    copy_term_nat(Ps0-L0, Ps-L),
+    bind_params(Ps, As, L).
+
+free_lambda(Free, M:(Ps0>>L0), As) :-
+    !,
+    copy_term_nat(Free/(Ps0>>L0), Free/(Ps>>L)),
+    bind_params(Ps, As, M:L).
+free_lambda(Free, L0, As) :-
+    copy_term_nat(Free/L0, Free/L),
+    G =.. [call, L|As],
+    call(G).
+
+bind_params([], As, L) :-
+    !,
+    G =.. [call, L|As],
+    call(G).
+bind_params([P|Ps], [A|As], L) :-
+    !,
+    P = A,
+    bind_params(Ps, As, L).
+bind_params(_, [], L) :-
+    call(L).
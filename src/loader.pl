@@ -35,40 +35,67 @@
     ),
     '$fail'.
 
+% a hook whose output keeps changing, say by growing or by cycling
+% between terms, would otherwise be re-expanded forever, so expansion
+% gives up with a resource error after this many rounds.
+expansion_depth_limit(1000).
+
+expansion_depth_error(Expansion) :-
+    throw(error(resource_error(expansion_depth), Expansion)).
+
 expand_term(Term, ExpandedTerm) :-
+    expand_term(Term, ExpandedTerm, 0).
+
+expand_term(Term, ExpandedTerm, Depth) :-
     (  catch('$call'(user:term_expansion(Term, ExpandedTerm0)),
              E,
              '$call'(loader:'$print_message_and_fail'(E))) ->
        (  var(ExpandedTerm0) ->
           error:instantiation_error(term_expansion/2)
-       ;  ExpandedTerm0 = [_|_] ->
-          term_expansion_list(ExpandedTerm0, ExpandedTerm, [])
-       ;  expand_term(ExpandedTerm0, ExpandedTerm)
+       ;  '$variant'(Term, ExpandedTerm0) ->
+          %% the hook reached a fixpoint. expanding its output again
+          %% would never terminate.
+          ExpandedTerm = ExpandedTerm0
+       ;  expansion_depth_limit(Depth) ->
+          expansion_depth_error(term_expansion/2)
+       ;  Depth1 is Depth + 1,
+          (  ExpandedTerm0 = [_|_] ->
+             term_expansion_list(ExpandedTerm0, ExpandedTerm, [], Depth1)
+          ;  expand_term(ExpandedTerm0, ExpandedTerm, Depth1)
+          )
        )
     ;  Term = ExpandedTerm
     ).
 
-term_expansion_list([], ExpandedTerms, ExpandedTerms).
-term_expansion_list([Term|Terms], ExpandedTermsHead, ExpandedTermsTail) :-
-    expand_term(Term, ExpandedTerm0),
+term_expansion_list([], ExpandedTerms, ExpandedTerms, _).
+term_expansion_list([Term|Terms], ExpandedTermsHead, ExpandedTermsTail, Depth) :-
+    expand_term(Term, ExpandedTerm0, Depth),
     (  var(ExpandedTerm0) ->
        error:instantiation_error(term_expansion/2)
     ;  ExpandedTerm0 = [_|_] ->
-       term_expansion_list(ExpandedTerm0, ExpandedTermsHead, ExpandedTerms0Tail),
-       term_expansion_list(Terms, ExpandedTerms0Tail, ExpandedTermsTail)
+       term_expansion_list(ExpandedTerm0, ExpandedTermsHead, ExpandedTerms0Tail, Depth),
+       term_expansion_list(Terms, ExpandedTerms0Tail, ExpandedTermsTail, Depth)
     ;  ExpandedTermsHead = [ExpandedTerm0 | ExpandedTerms0Tail],
-       term_expansion_list(Terms, ExpandedTerms0Tail, ExpandedTermsTail)
+       term_expansion_list(Terms, ExpandedTerms0Tail, ExpandedTermsTail, Depth)
     ).
 
 
 goal_expansion(Goal, Module, ExpandedGoal) :-
+    goal_expansion(Goal, Module, ExpandedGoal, 0).
+
+goal_expansion(Goal, Module, ExpandedGoal, Depth) :-
     (  atom(Module),
        catch('$call'(Module:goal_expansion(Goal, ExpandedGoal0)),
              E,
              '$call'(loader:'$print_message_and_fail'(E))) ->
        (  var(ExpandedGoal0) ->
           error:instantiation_error(goal_expansion/2)
-       ;  goal_expansion(ExpandedGoal0, Module, ExpandedGoal)
+       ;  '$variant'(Goal, ExpandedGoal0) ->
+          ExpandedGoal = ExpandedGoal0
+       ;  expansion_depth_limit(Depth) ->
+          expansion_depth_error(goal_expansion/2)
+       ;  Depth1 is Depth + 1,
+          goal_expansion(ExpandedGoal0, Module, ExpandedGoal, Depth1)
        )
     ;  Goal = ExpandedGoal
     ).
@@ -530,6 +557,26 @@ check_predicate_property(multifile, Module, Name, Arity, multifile) :-
     '$cpp_multifile_property'(Module, Name, Arity).
 check_predicate_property(discontiguous, Module, Name, Arity, discontiguous) :-
     '$cpp_discontiguous_property'(Module, Name, Arity).
+check_predicate_property(defined, Module, Name, Arity, defined) :-
+    (  '$cpp_dynamic_property'(Module, Name, Arity) ->
+       true
+    ;  functor(Head, Name, Arity),
+       \+ '$no_such_predicate'(Module, Head)
+    ).
+check_predicate_property(static, Module, Name, Arity, static) :-
+    \+ '$cpp_dynamic_property'(Module, Name, Arity),
+    functor(Head, Name, Arity),
+    \+ '$no_such_predicate'(Module, Head).
+check_predicate_property(number_of_clauses, Module, Name, Arity, number_of_clauses(N)) :-
+    '$cpp_dynamic_property'(Module, Name, Arity),
+    functor(Head, Name, Arity),
+    % '$clause'/2 honors the logical update view, so retracted
+    % clauses are not counted.
+    (  Module == user ->
+       findall(Head, builtins:'$clause'(Head, _), Clauses)
+    ;  findall(Head, Module:'$clause'(Head, _), Clauses)
+    ),
+    length(Clauses, N).
 
 
 
@@ -116,6 +116,48 @@ impl MachineState {
         }
     }
 
+    pub(crate) fn evaluable_error(
+        &self,
+        name: ClauseName,
+        arity: usize,
+        caller: MachineStub,
+    ) -> MachineStub {
+        let evaluable_stub = MachineError::functor_stub(name, arity);
+        let err = MachineError::type_error(self.heap.h(), ValidType::Evaluable, evaluable_stub);
+
+        self.error_form(err, caller)
+    }
+
+    // the error for a cell that doesn't evaluate to a number. atoms
+    // and compound terms are type_error(evaluable, Name/Arity),
+    // variables are instantiation errors and anything else is
+    // type_error(number, X).
+    pub(crate) fn non_number_error(
+        &self,
+        value: &HeapCellValue,
+        caller: MachineStub,
+    ) -> MachineStub {
+        let (name, arity) = match value {
+            &HeapCellValue::NamedStr(arity, ref name, _) => (name.clone(), arity),
+            &HeapCellValue::Atom(ref name, _) => (name.clone(), 0),
+            &HeapCellValue::Addr(Addr::Char(c)) => (clause_name!(c.to_string(), self.atom_tbl), 0),
+            &HeapCellValue::Addr(addr) if addr.is_ref() => {
+                return self.error_form(MachineError::instantiation_error(), caller);
+            }
+            val => {
+                let err = MachineError::type_error(
+                    self.heap.h(),
+                    ValidType::Number,
+                    val.context_free_clone(),
+                );
+
+                return self.error_form(err, caller);
+            }
+        };
+
+        self.evaluable_error(name, arity, caller)
+    }
+
     pub(crate) fn arith_eval_by_metacall(&self, r: RegType) -> Result<Number, MachineStub> {
         let caller = MachineError::functor_stub(clause_name!("is"), 2);
         let mut interms: Vec<Number> = Vec::with_capacity(64);
@@ -162,16 +204,7 @@ impl MachineState {
                         "atan2" => interms.push(Number::Float(OrderedFloat(self.atan2(a1, a2)?))),
                         "gcd" => interms.push(self.gcd(a1, a2)?),
                         _ => {
-                            let evaluable_stub = MachineError::functor_stub(name.clone(), 2);
-
-                            return Err(self.error_form(
-                                MachineError::type_error(
-                                    self.heap.h(),
-                                    ValidType::Evaluable,
-                                    evaluable_stub,
-                                ),
-                                caller,
-                            ));
+                            return Err(self.evaluable_error(name.clone(), 2, caller));
                         }
                     }
                 }
@@ -199,16 +232,7 @@ impl MachineState {
                         "\\" => interms.push(self.bitwise_complement(a1)?),
                         "sign" => interms.push(self.sign(a1)),
                         _ => {
-                            let evaluable_stub = MachineError::functor_stub(name.clone(), 1);
-
-                            return Err(self.error_form(
-                                MachineError::type_error(
-                                    self.heap.h(),
-                                    ValidType::Evaluable,
-                                    evaluable_stub,
-                                ),
-                                caller,
-                            ));
+                            return Err(self.evaluable_error(name.clone(), 1, caller));
                         }
                     }
                 }
@@ -227,40 +251,8 @@ impl MachineState {
                 &HeapCellValue::Atom(ref name, _) if name.as_str() == "e" => {
                     interms.push(Number::Float(OrderedFloat(f64::consts::E)))
                 }
-                &HeapCellValue::NamedStr(arity, ref name, _) => {
-                    let evaluable_stub = MachineError::functor_stub(name.clone(), arity);
-
-                    return Err(self.error_form(
-                        MachineError::type_error(
-                            self.heap.h(),
-                            ValidType::Evaluable,
-                            evaluable_stub,
-                        ),
-                        caller,
-                    ));
-                }
-                &HeapCellValue::Atom(ref name, _) => {
-                    let evaluable_stub = MachineError::functor_stub(name.clone(), 0);
-
-                    return Err(self.error_form(
-                        MachineError::type_error(
-                            self.heap.h(),
-                            ValidType::Evaluable,
-                            evaluable_stub,
-                        ),
-                        caller,
-                    ));
-                }
-                &HeapCellValue::Addr(addr) if addr.is_ref() => {
-                    return Err(self.error_form(MachineError::instantiation_error(), caller));
-                }
                 val => {
-                    return Err(self.type_error(
-                        ValidType::Number,
-                        val.context_free_clone(),
-                        clause_name!("is"),
-                        2,
-                    ));
+                    return Err(self.non_number_error(val, caller));
                 }
             }
         }
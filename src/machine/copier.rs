@@ -18,6 +18,12 @@ pub(crate) trait CopierTarget: IndexMut<usize, Output = HeapCellValue> {
     fn stack(&mut self) -> &mut Stack;
     fn store(&self, val: Addr) -> Addr;
     fn threshold(&self) -> usize;
+
+    // an existing copy of the list or structure at addr, which is
+    // then referred to instead of copied.
+    fn shared_copy(&self, _addr: Addr) -> Option<Addr> {
+        None
+    }
 }
 
 pub(crate) fn copy_term<T: CopierTarget>(target: T, addr: Addr, attr_var_policy: AttrVarPolicy) {
@@ -253,6 +259,9 @@ impl<T: CopierTarget> CopyTermState<T> {
                     Addr::Lis(h) => {
                         if h >= self.old_h {
                             self.scan += 1;
+                        } else if let Some(addr) = self.target.shared_copy(Addr::Lis(h)) {
+                            *self.value_at_scan() = HeapCellValue::Addr(addr);
+                            self.scan += 1;
                         } else {
                             self.copy_list(h);
                         }
@@ -263,7 +272,12 @@ impl<T: CopierTarget> CopyTermState<T> {
                         self.copy_var(addr);
                     }
                     Addr::Str(addr) => {
-                        self.copy_structure(addr);
+                        if let Some(addr) = self.target.shared_copy(Addr::Str(addr)) {
+                            *self.value_at_scan() = HeapCellValue::Addr(addr);
+                            self.scan += 1;
+                        } else {
+                            self.copy_structure(addr);
+                        }
                     }
                     Addr::PStrLocation(addr, n) => {
                         self.copy_partial_string(addr, n);
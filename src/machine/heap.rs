@@ -337,6 +337,12 @@ impl<T: RawBlockTraits> HeapTemplate<T> {
         (self.buf.top as usize - self.buf.base as usize) / mem::size_of::<HeapCellValue>()
     }
 
+    // the number of cells that fit in the heap before it must grow.
+    #[inline]
+    pub(crate) fn free_cells(&self) -> usize {
+        self.buf.free_space() / mem::size_of::<HeapCellValue>()
+    }
+
     pub(crate) fn append(&mut self, vals: Vec<HeapCellValue>) {
         for val in vals {
             self.push(val);
@@ -778,8 +778,10 @@ impl<'a, TS: TermStream> Loader<'a, TS> {
             Declaration::NonCountedBacktracking(name, arity) => {
                 self.non_counted_bt_preds.insert((name, arity));
             }
-            Declaration::Op(op_decl) => {
-                self.load_state.add_op_decl(&op_decl);
+            Declaration::Op(op_decls) => {
+                for op_decl in op_decls {
+                    self.load_state.add_op_decl(&op_decl);
+                }
             }
             Declaration::UseModule(module_src) => {
                 self.load_state.use_module(module_src)?;
@@ -1826,6 +1828,45 @@ impl Machine {
         }
     }
 
+    pub(crate) fn declare_dynamic_predicate(&mut self) {
+        let module_name = atom_from!(
+            self.machine_st,
+            self.machine_st
+                .store(self.machine_st.deref(self.machine_st[temp_v!(1)]))
+        );
+
+        let key = self
+            .machine_st
+            .read_predicate_key(self.machine_st[temp_v!(2)], self.machine_st[temp_v!(3)]);
+
+        // errors are reported on behalf of the predicate indicated by
+        // the last two arguments.
+        let error_pi = self
+            .machine_st
+            .read_predicate_key(self.machine_st[temp_v!(4)], self.machine_st[temp_v!(5)]);
+
+        let compilation_target = match module_name.as_str() {
+            "user" => CompilationTarget::User,
+            _ => CompilationTarget::Module(module_name),
+        };
+
+        let declare_dynamic_predicate = || {
+            let mut loader = Loader::new(LiveTermStream::new(ListingSource::User), self);
+            loader.load_state.compilation_target = compilation_target.clone();
+
+            loader.add_dynamic_predicate(compilation_target, key.0, key.1)?;
+
+            LiveTermStream::evacuate(loader)
+        };
+
+        match declare_dynamic_predicate() {
+            Ok(_) => {}
+            Err(e) => {
+                self.throw_session_error(e, error_pi);
+            }
+        }
+    }
+
     pub(crate) fn abolish_clause(&mut self) {
         let module_name = atom_from!(
             self.machine_st,
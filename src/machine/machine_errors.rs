@@ -375,6 +375,14 @@ impl MachineError {
             return Self::arithmetic_error(h, err);
         }
 
+        if let CompilationError::ExceededMaxArity = err {
+            return Self::representation_error(RepFlag::MaxArity);
+        }
+
+        if let CompilationError::ParserError(ref err @ ParserError::ResourceError(..)) = err {
+            return Self::resource_error(err.as_str());
+        }
+
         let location = err.line_and_col_num();
         let stub = err.as_functor(h);
 
@@ -387,6 +395,16 @@ impl MachineError {
         }
     }
 
+    pub(super) fn resource_error(resource: &'static str) -> Self {
+        let stub = functor!("resource_error", [atom(resource)]);
+
+        MachineError {
+            stub,
+            location: None,
+            from: ErrorProvenance::Received,
+        }
+    }
+
     pub(super) fn representation_error(flag: RepFlag) -> Self {
         let stub = functor!("representation_error", [atom(flag.as_str())]);
 
@@ -420,6 +438,7 @@ pub(crate) enum CompilationError {
     ParserError(ParserError),
     // BadPendingByte,
     CannotParseCyclicTerm,
+    ExceededMaxArity,
     // ExpandedTermsListNotAList,
     ExpectedRel,
     // ExpectedTopLevelTerm,
@@ -465,6 +484,7 @@ impl CompilationError {
             // &CompilationError::BadPendingByte =>
             //     functor!("bad_pending_byte"),
             &CompilationError::CannotParseCyclicTerm => functor!("cannot_parse_cyclic_term"),
+            &CompilationError::ExceededMaxArity => functor!("exceeded_max_arity"),
             // &CompilationError::ExpandedTermsListNotAList =>
             //     functor!("expanded_terms_list_is_not_a_list"),
             &CompilationError::ExpectedRel => functor!("expected_relation"),
@@ -533,6 +553,7 @@ pub(crate) enum ValidType {
     InCharacter,
     Integer,
     List,
+    NotLessThanZero,
     Number,
     Pair,
     //    PredicateIndicator,
@@ -556,6 +577,7 @@ impl ValidType {
             ValidType::InCharacter => "in_character",
             ValidType::Integer => "integer",
             ValidType::List => "list",
+            ValidType::NotLessThanZero => "not_less_than_zero",
             ValidType::Number => "number",
             ValidType::Pair => "pair",
             //            ValidType::PredicateIndicator => "predicate_indicator",
@@ -568,6 +590,7 @@ impl ValidType {
 #[derive(Debug, Clone, Copy)]
 pub(crate) enum DomainErrorType {
     IOMode,
+    NonZero,
     NotLessThanZero,
     Order,
     SourceSink,
@@ -579,6 +602,7 @@ impl DomainErrorType {
     pub(crate) fn as_str(self) -> &'static str {
         match self {
             DomainErrorType::IOMode => "io_mode",
+            DomainErrorType::NonZero => "nonzero",
             DomainErrorType::NotLessThanZero => "not_less_than_zero",
             DomainErrorType::Order => "order",
             DomainErrorType::SourceSink => "source_sink",
@@ -595,7 +619,7 @@ pub(crate) enum RepFlag {
     CharacterCode,
     InCharacterCode,
     MaxArity,
-    //    MaxInteger,
+    MaxInteger,
     //    MinInteger,
     Term,
 }
@@ -607,8 +631,8 @@ impl RepFlag {
             RepFlag::CharacterCode => "character_code",
             RepFlag::InCharacterCode => "in_character_code",
             RepFlag::MaxArity => "max_arity",
+            RepFlag::MaxInteger => "max_integer",
             RepFlag::Term => "term",
-            //            RepFlag::MaxInteger => "max_integer",
             //            RepFlag::MinInteger => "min_integer"
         }
     }
@@ -647,8 +671,8 @@ pub(super) enum CycleSearchResult {
 
 impl MachineState {
     // see 8.4.3 of Draft Technical Corrigendum 2.
-    pub(super) fn check_sort_errors(&self) -> CallResult {
-        let stub = MachineError::functor_stub(clause_name!("sort"), 2);
+    pub(super) fn check_sort_errors(&self, name: &'static str) -> CallResult {
+        let stub = MachineError::functor_stub(clause_name!(name), 2);
         let list = self.store(self.deref(self[temp_v!(1)].clone()));
         let sorted = self.store(self.deref(self[temp_v!(2)].clone()));
 
@@ -656,6 +680,9 @@ impl MachineState {
             CycleSearchResult::PartialList(..) => {
                 return Err(self.error_form(MachineError::instantiation_error(), stub))
             }
+            CycleSearchResult::NotList if list.is_ref() => {
+                return Err(self.error_form(MachineError::instantiation_error(), stub))
+            }
             CycleSearchResult::NotList => {
                 return Err(
                     self.error_form(MachineError::type_error(0, ValidType::List, list), stub)
@@ -690,9 +717,7 @@ impl MachineState {
                             HeapCellValue::Addr(Addr::Str(l)) => {
                                 new_l = l;
                             }
-                            HeapCellValue::NamedStr(2, ref name, Some(_))
-                                if name.as_str() == "-" =>
-                            {
+                            HeapCellValue::NamedStr(2, ref name, _) if name.as_str() == "-" => {
                                 break;
                             }
                             HeapCellValue::Addr(Addr::HeapCell(_)) => {
@@ -729,6 +754,9 @@ impl MachineState {
             CycleSearchResult::PartialList(..) => {
                 Err(self.error_form(MachineError::instantiation_error(), stub))
             }
+            CycleSearchResult::NotList if pairs.is_ref() => {
+                Err(self.error_form(MachineError::instantiation_error(), stub))
+            }
             CycleSearchResult::NotList => {
                 Err(self.error_form(MachineError::type_error(0, ValidType::List, pairs), stub))
             }
@@ -778,12 +806,17 @@ impl MachineState {
 
         stub.extend(err.into_iter(3));
 
-        if let Some((line_num, _)) = location {
+        // errors carrying a source location are raised with the
+        // context Src:position(LineNum, ColNum).
+        if let Some((line_num, col_num)) = location {
             let colon_op_desc = Some(SharedOpDesc::new(600, XFY));
 
             stub.push(HeapCellValue::NamedStr(2, clause_name!(":"), colon_op_desc));
+            stub.push(HeapCellValue::Addr(Addr::HeapCell(h + 9 + err_len)));
             stub.push(HeapCellValue::Addr(Addr::HeapCell(h + 6 + err_len)));
+            stub.push(HeapCellValue::NamedStr(2, clause_name!("position"), None));
             stub.push(HeapCellValue::Integer(Rc::new(Integer::from(line_num))));
+            stub.push(HeapCellValue::Integer(Rc::new(Integer::from(col_num))));
         }
 
         stub.extend(src.into_iter());
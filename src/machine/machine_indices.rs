@@ -447,6 +447,7 @@ pub(crate) enum REPLCodePtr {
     DiscontiguousProperty,
     DynamicProperty,
     AbolishClause,
+    DeclareDynamicPredicate,
     Asserta,
     Assertz,
     Retract,
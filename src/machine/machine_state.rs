@@ -13,6 +13,7 @@ use crate::machine::machine_indices::*;
 use crate::machine::partial_string::HeapPStrIter;
 use crate::machine::stack::*;
 use crate::machine::streams::*;
+use crate::read::{write_layout_to_heap, ReadTermOptions, ReadTermVars, TermWriteResult};
 use crate::rug::Integer;
 
 use downcast::{
@@ -117,6 +118,7 @@ pub(super) struct CopyBallTerm<'a> {
     heap: &'a mut Heap,
     heap_boundary: usize,
     stub: &'a mut Heap,
+    shared_copies: Option<&'a IndexMap<Addr, usize>>,
 }
 
 impl<'a> CopyBallTerm<'a> {
@@ -128,6 +130,41 @@ impl<'a> CopyBallTerm<'a> {
             heap,
             heap_boundary: hb,
             stub,
+            shared_copies: None,
+        }
+    }
+
+    // heap subterms found in shared_copies aren't copied again but
+    // referred to at the stub location they map to.
+    pub(super) fn with_shared_copies(
+        mut self,
+        shared_copies: Option<&'a IndexMap<Addr, usize>>,
+    ) -> Self {
+        self.shared_copies = shared_copies;
+        self
+    }
+}
+
+// the ground subterms of a findall/3 template that were built before
+// its goal was called, mapped to the lifted heap locations of their
+// first copies. a ground subterm below heap_boundary none of whose
+// cells were trailed past trail_boundary can't change while the
+// solutions are collected, so later solutions share its copy.
+#[derive(Debug)]
+pub(super) struct LiftedHeapGroundCache {
+    pub(super) lh_offset: usize,
+    pub(super) heap_boundary: usize,
+    pub(super) trail_boundary: usize,
+    pub(super) copies: IndexMap<Addr, usize>,
+}
+
+impl LiftedHeapGroundCache {
+    pub(super) fn new(lh_offset: usize, heap_boundary: usize, trail_boundary: usize) -> Self {
+        LiftedHeapGroundCache {
+            lh_offset,
+            heap_boundary,
+            trail_boundary,
+            copies: IndexMap::new(),
         }
     }
 }
@@ -196,6 +233,16 @@ impl<'a> CopierTarget for CopyBallTerm<'a> {
     fn stack(&mut self) -> &mut Stack {
         self.stack
     }
+
+    fn shared_copy(&self, addr: Addr) -> Option<Addr> {
+        let lh_index = *self.shared_copies?.get(&addr)?;
+
+        match addr {
+            Addr::Lis(_) => Some(Addr::Lis(self.heap_boundary + lh_index)),
+            Addr::Str(_) => Some(Addr::Str(self.heap_boundary + lh_index)),
+            _ => None,
+        }
+    }
 }
 
 impl Index<RegType> for MachineState {
@@ -297,12 +344,15 @@ pub(crate) struct MachineState {
     pub(super) block: usize, // an offset into the OR stack.
     pub(super) ball: Ball,
     pub(super) lifted_heap: Heap,
+    pub(super) lh_ground_caches: Vec<LiftedHeapGroundCache>,
     pub(super) interms: Vec<Number>, // intermediate numbers.
     pub(super) last_call: bool,
     pub(crate) flags: MachineFlags,
     pub(crate) cc: usize,
     pub(crate) global_clock: usize,
+    pub(crate) inference_count: usize,
     pub(crate) dynamic_mode: FirstOrNext,
+    pub(super) unknown_warning_loc: usize, // location of '$warn_unknown_procedure'/1.
     pub(crate) unify_fn: fn(&mut MachineState, Addr, Addr),
     pub(crate) bind_fn: fn(&mut MachineState, Ref, Addr),
 }
@@ -330,12 +380,15 @@ impl fmt::Debug for MachineState {
             .field("block", &self.block)
             .field("ball", &self.ball)
             .field("lifted_heap", &self.lifted_heap)
+            .field("lh_ground_caches", &self.lh_ground_caches)
             .field("interms", &self.interms)
             .field("last_call", &self.last_call)
             .field("flags", &self.flags)
             .field("cc", &self.cc)
             .field("global_clock", &self.global_clock)
+            .field("inference_count", &self.inference_count)
             .field("dynamic_mode", &self.dynamic_mode)
+            .field("unknown_warning_loc", &self.unknown_warning_loc)
             .field(
                 "unify_fn",
                 if self.unify_fn as usize == MachineState::unify as usize {
@@ -362,32 +415,124 @@ impl fmt::Debug for MachineState {
     }
 }
 
+fn push_var_eq_functors<'a>(
+    heap: &mut Heap,
+    iter: impl Iterator<Item = (&'a Rc<Var>, &'a Addr)>,
+    op_dir: &OpDir,
+    atom_tbl: TabledData<Atom>,
+) -> Vec<Addr> {
+    let mut list_of_var_eqs = vec![];
+
+    for (var, binding) in iter {
+        let var_atom = clause_name!(var.to_string(), atom_tbl);
+
+        let h = heap.h();
+        let spec = fetch_atom_op_spec(clause_name!("="), None, op_dir);
+
+        heap.push(HeapCellValue::NamedStr(2, clause_name!("="), spec));
+        heap.push(HeapCellValue::Atom(var_atom, None));
+        heap.push(HeapCellValue::Addr(*binding));
+
+        list_of_var_eqs.push(Addr::Str(h));
+    }
+
+    list_of_var_eqs
+}
+
 impl MachineState {
-    pub(crate) fn read_term(&mut self, mut stream: Stream, indices: &mut IndexStore) -> CallResult {
-        fn push_var_eq_functors<'a>(
-            heap: &mut Heap,
-            iter: impl Iterator<Item = (&'a Rc<Var>, &'a Addr)>,
-            op_dir: &OpDir,
-            atom_tbl: TabledData<Atom>,
-        ) -> Vec<Addr> {
-            let mut list_of_var_eqs = vec![];
-
-            for (var, binding) in iter {
-                let var_atom = clause_name!(var.to_string(), atom_tbl);
-
-                let h = heap.h();
-                let spec = fetch_atom_op_spec(clause_name!("="), None, op_dir);
-
-                heap.push(HeapCellValue::NamedStr(2, clause_name!("="), spec));
-                heap.push(HeapCellValue::Atom(var_atom, None));
-                heap.push(HeapCellValue::Addr(*binding));
-
-                list_of_var_eqs.push(Addr::Str(h));
+    pub(crate) fn read_term_vars(
+        &mut self,
+        term_write_result: &TermWriteResult,
+        options: ReadTermOptions,
+        op_dir: &OpDir,
+    ) -> ReadTermVars {
+        let mut read_term_vars = ReadTermVars::default();
+
+        if options.variable_names {
+            let list_of_var_eqs = push_var_eq_functors(
+                &mut self.heap,
+                term_write_result.var_dict.iter(),
+                op_dir,
+                self.atom_tbl.clone(),
+            );
+
+            read_term_vars.variable_names = Some(Addr::HeapCell(
+                self.heap.to_list(list_of_var_eqs.into_iter()),
+            ));
+        }
+
+        if !(options.singletons || options.variables) {
+            return read_term_vars;
+        }
+
+        let mut singleton_var_set: IndexMap<Ref, bool> = IndexMap::new();
+        let mut var_list = vec![];
+
+        for addr in self.acyclic_pre_order_iter(Addr::HeapCell(term_write_result.heap_loc)) {
+            if let Some(var) = addr.as_var() {
+                if !singleton_var_set.contains_key(&var) {
+                    singleton_var_set.insert(var, true);
+                    var_list.push(addr);
+                } else {
+                    singleton_var_set.insert(var, false);
+                }
             }
+        }
 
-            list_of_var_eqs
+        if options.singletons {
+            let singleton_var_list = push_var_eq_functors(
+                &mut self.heap,
+                term_write_result.var_dict.iter().filter(|(_, binding)| {
+                    if let Some(r) = binding.as_var() {
+                        *singleton_var_set.get(&r).unwrap_or(&false)
+                    } else {
+                        false
+                    }
+                }),
+                op_dir,
+                self.atom_tbl.clone(),
+            );
+
+            read_term_vars.singletons = Some(Addr::HeapCell(
+                self.heap.to_list(singleton_var_list.into_iter()),
+            ));
+        }
+
+        if options.variables {
+            read_term_vars.variables =
+                Some(Addr::HeapCell(self.heap.to_list(var_list.into_iter())));
         }
 
+        read_term_vars
+    }
+
+    // unifies the lists of read_term_vars against the singletons,
+    // variables and variable_names arguments in registers 3 to 5.
+    pub(crate) fn unify_read_term_vars(&mut self, read_term_vars: ReadTermVars) {
+        let lists = [
+            (read_term_vars.singletons, temp_v!(3)),
+            (read_term_vars.variables, temp_v!(4)),
+            (read_term_vars.variable_names, temp_v!(5)),
+        ];
+
+        for (list, r) in lists.iter() {
+            if let Some(list) = list {
+                let target = self[*r];
+                (self.unify_fn)(self, *list, target);
+
+                if self.fail {
+                    return;
+                }
+            }
+        }
+    }
+
+    pub(crate) fn read_term(
+        &mut self,
+        mut stream: Stream,
+        indices: &mut IndexStore,
+        layout: Option<Addr>,
+    ) -> CallResult {
         self.check_stream_properties(
             &mut stream,
             StreamType::Text,
@@ -407,8 +552,13 @@ impl MachineState {
         let mut orig_stream = stream.clone();
 
         loop {
-            match self.read(stream.clone(), self.atom_tbl.clone(), &indices.op_dir) {
-                Ok(term_write_result) => {
+            match self.read_with_layout(
+                stream.clone(),
+                self.atom_tbl.clone(),
+                &indices.op_dir,
+                layout.is_some(),
+            ) {
+                Ok((term_write_result, term_layout)) => {
                     let term = self[temp_v!(2)];
                     (self.unify_fn)(self, Addr::HeapCell(term_write_result.heap_loc), term);
 
@@ -416,64 +566,22 @@ impl MachineState {
                         return Ok(());
                     }
 
-                    let list_of_var_eqs = push_var_eq_functors(
-                        &mut self.heap,
-                        term_write_result.var_dict.iter(),
-                        &indices.op_dir,
-                        self.atom_tbl.clone(),
-                    );
+                    if let (Some(layout), Some(term_layout)) = (layout, term_layout) {
+                        let term_layout = write_layout_to_heap(&term_layout, self);
+                        (self.unify_fn)(self, term_layout, layout);
 
-                    let mut singleton_var_set: IndexMap<Ref, bool> = IndexMap::new();
-                    let mut var_list = vec![];
-
-                    for addr in self.acyclic_pre_order_iter(term) {
-                        if let Some(var) = addr.as_var() {
-                            if !singleton_var_set.contains_key(&var) {
-                                singleton_var_set.insert(var, true);
-                                var_list.push(addr);
-                            } else {
-                                singleton_var_set.insert(var, false);
-                            }
+                        if self.fail {
+                            return Ok(());
                         }
                     }
 
-                    let singleton_var_list = push_var_eq_functors(
-                        &mut self.heap,
-                        term_write_result.var_dict.iter().filter(|(_, binding)| {
-                            if let Some(r) = binding.as_var() {
-                                *singleton_var_set.get(&r).unwrap_or(&false)
-                            } else {
-                                false
-                            }
-                        }),
+                    let read_term_vars = self.read_term_vars(
+                        &term_write_result,
+                        ReadTermOptions::all(),
                         &indices.op_dir,
-                        self.atom_tbl.clone(),
                     );
 
-                    let singleton_addr = self[temp_v!(3)];
-                    let singletons_offset =
-                        Addr::HeapCell(self.heap.to_list(singleton_var_list.into_iter()));
-
-                    (self.unify_fn)(self, singletons_offset, singleton_addr);
-
-                    if self.fail {
-                        return Ok(());
-                    }
-
-                    let vars_addr = self[temp_v!(4)];
-                    let vars_offset = Addr::HeapCell(self.heap.to_list(var_list.into_iter()));
-
-                    (self.unify_fn)(self, vars_offset, vars_addr);
-
-                    if self.fail {
-                        return Ok(());
-                    }
-
-                    let var_names_addr = self[temp_v!(5)];
-                    let var_names_offset =
-                        Addr::HeapCell(self.heap.to_list(list_of_var_eqs.into_iter()));
-
-                    return Ok((self.unify_fn)(self, var_names_offset, var_names_addr));
+                    return Ok(self.unify_read_term_vars(read_term_vars));
                 }
                 Err(err) => {
                     if let ParserError::UnexpectedEOF = err {
@@ -614,6 +722,35 @@ impl MachineState {
         self.error_form(MachineError::existence_error(h, key), stub)
     }
 
+    // calls to undefined procedures error, fail or warn and fail
+    // according to the unknown flag. the warning is written by
+    // '$warn_unknown_procedure'/1, which is called in place of the
+    // undefined procedure and then fails.
+    pub(super) fn undefined_procedure(&mut self, name: ClauseName, arity: usize) -> CallResult {
+        match self.flags.unknown {
+            Unknown::Error => Err(self.throw_undefined_error(name, arity)),
+            Unknown::Fail => {
+                self.fail = true;
+                Ok(())
+            }
+            Unknown::Warn => {
+                let h = self.heap.h();
+                let stub = MachineError::functor_stub(name, arity);
+
+                self.heap.extend(stub.into_iter());
+                self[temp_v!(1)] = Addr::Str(h);
+
+                if self.last_call {
+                    self.execute_at_index(1, dir_entry!(self.unknown_warning_loc));
+                } else {
+                    self.call_at_index(1, dir_entry!(self.unknown_warning_loc));
+                }
+
+                Ok(())
+            }
+        }
+    }
+
     #[inline]
     pub(crate) fn heap_pstr_iter<'a>(&'a self, focus: Addr) -> HeapPStrIter<'a> {
         HeapPStrIter::new(self, focus)
@@ -650,6 +787,39 @@ impl MachineState {
         Ok(chars)
     }
 
+    pub(super) fn try_code_list(&self, addrs: Vec<Addr>) -> Result<String, MachineError> {
+        let mut chars = String::new();
+
+        for addr in addrs {
+            let addr = self.store(self.deref(addr));
+
+            let code = match addr {
+                Addr::Char(c) => {
+                    chars.push(c);
+                    continue;
+                }
+                _ => match Number::try_from((addr, &self.heap)) {
+                    Ok(Number::Fixnum(n)) => Some(u32::try_from(n).ok()),
+                    Ok(Number::Integer(n)) => Some(n.to_u32()),
+                    _ => None,
+                },
+            };
+
+            match code {
+                Some(code) => match code.and_then(std::char::from_u32) {
+                    Some(c) => chars.push(c),
+                    None => return Err(MachineError::representation_error(RepFlag::CharacterCode)),
+                },
+                None => {
+                    let h = self.heap.h();
+                    return Err(MachineError::type_error(h, ValidType::Integer, addr));
+                }
+            }
+        }
+
+        Ok(chars)
+    }
+
     pub(super) fn read_predicate_key(&self, name: Addr, arity: Addr) -> (ClauseName, usize) {
         let predicate_name = atom_from!(self, self.store(self.deref(name)));
         let arity = self.store(self.deref(arity));
@@ -973,7 +1143,7 @@ pub(crate) trait CallPolicy: Any + fmt::Debug {
                 return Ok(());
             }
             IndexPtr::Undefined => {
-                return Err(machine_st.throw_undefined_error(name, arity));
+                return machine_st.undefined_procedure(name, arity);
             }
             IndexPtr::DynamicIndex(compiled_tl_index) => {
                 machine_st.dynamic_mode = FirstOrNext::First;
@@ -1000,7 +1170,7 @@ pub(crate) trait CallPolicy: Any + fmt::Debug {
                 return Ok(());
             }
             IndexPtr::Undefined => {
-                return Err(machine_st.throw_undefined_error(name, arity));
+                return machine_st.undefined_procedure(name, arity);
             }
             IndexPtr::DynamicIndex(compiled_tl_index) => {
                 machine_st.dynamic_mode = FirstOrNext::First;
@@ -1136,7 +1306,13 @@ pub(crate) trait CallPolicy: Any + fmt::Debug {
                 let a1 = machine_st[temp_v!(1)];
                 let a2 = machine_st[temp_v!(2)];
 
-                machine_st.fail = machine_st.eq_test(a1, a2);
+                machine_st.fail =
+                    if let Some(Ordering::Equal) = machine_st.compare_term_test(&a1, &a2) {
+                        false
+                    } else {
+                        true
+                    };
+
                 return_from_clause!(machine_st.last_call, machine_st)
             }
             &BuiltInClauseType::Ground => {
@@ -1161,7 +1337,7 @@ pub(crate) trait CallPolicy: Any + fmt::Debug {
                 return_from_clause!(machine_st.last_call, machine_st)
             }
             &BuiltInClauseType::Sort => {
-                machine_st.check_sort_errors()?;
+                machine_st.check_sort_errors("sort")?;
 
                 let stub = MachineError::functor_stub(clause_name!("sort"), 2);
                 let mut list = machine_st.try_from_list(temp_v!(1), stub)?;
@@ -1262,7 +1438,7 @@ pub(crate) trait CallPolicy: Any + fmt::Debug {
                 if let Some(idx) = code_dir.get(&(name.clone(), arity)) {
                     self.context_call(machine_st, name, arity, idx)?;
                 } else {
-                    return Err(machine_st.throw_undefined_error(name, arity));
+                    return machine_st.undefined_procedure(name, arity);
                 }
             }
             ClauseType::System(_) => {
@@ -50,12 +50,15 @@ impl MachineState {
             block: 0,
             ball: Ball::new(),
             lifted_heap: Heap::new(),
+            lh_ground_caches: vec![],
             interms: vec![Number::default(); 256],
             last_call: false,
             flags: MachineFlags::default(),
             cc: 0,
             global_clock: 0,
+            inference_count: 0,
             dynamic_mode: FirstOrNext::First,
+            unknown_warning_loc: 0,
             unify_fn: MachineState::unify,
             bind_fn: MachineState::bind,
         }
@@ -66,6 +69,23 @@ impl MachineState {
         self.flags
     }
 
+    // the used and free cells of the heap, the stack of environments
+    // and choice points, and the trail, as reported by statistics/2.
+    #[inline]
+    pub(crate) fn global_stack_usage(&self) -> (usize, usize) {
+        (self.heap.h(), self.heap.free_cells())
+    }
+
+    #[inline]
+    pub(crate) fn local_stack_usage(&self) -> (usize, usize) {
+        (self.stack.used_cells(), self.stack.free_cells())
+    }
+
+    #[inline]
+    pub(crate) fn trail_usage(&self) -> (usize, usize) {
+        (self.trail.len(), self.trail.capacity() - self.trail.len())
+    }
+
     pub(crate) fn store(&self, addr: Addr) -> Addr {
         match addr {
             Addr::AttrVar(h) | Addr::HeapCell(h) => self.heap[h].as_addr(h),
@@ -1963,113 +1983,6 @@ impl MachineState {
     }
 
     // returns true on failure.
-    pub(super) fn eq_test(&self, a1: Addr, a2: Addr) -> bool {
-        let mut iter = self.zipped_acyclic_pre_order_iter(a1, a2);
-
-        while let Some((v1, v2)) = iter.next() {
-            match (v1, v2) {
-                (Addr::Str(s1), Addr::Str(s2)) => {
-                    if let HeapCellValue::NamedStr(ar1, n1, _) = &self.heap[s1] {
-                        if let HeapCellValue::NamedStr(ar2, n2, _) = &self.heap[s2] {
-                            if ar1 != ar2 || n1 != n2 {
-                                return true;
-                            }
-                        } else {
-                            unreachable!()
-                        }
-                    } else {
-                        unreachable!()
-                    }
-                }
-                (Addr::PStrLocation(..), Addr::Lis(_)) | (Addr::Lis(_), Addr::PStrLocation(..)) => {
-                    continue;
-                }
-                (pstr1 @ Addr::PStrLocation(..), pstr2 @ Addr::PStrLocation(..)) => {
-                    let mut i1 = self.heap_pstr_iter(pstr1);
-                    let mut i2 = self.heap_pstr_iter(pstr2);
-
-                    let ordering = compare_pstr_prefixes(&mut i1, &mut i2);
-
-                    if let Some(ordering) = ordering {
-                        if ordering != Ordering::Equal {
-                            return true;
-                        }
-                    }
-
-                    let (lstack, rstack) = iter.stack();
-
-                    lstack.pop();
-                    lstack.pop();
-
-                    rstack.pop();
-                    rstack.pop();
-
-                    lstack.push(i1.focus());
-                    rstack.push(i2.focus());
-                }
-                (Addr::Lis(_), Addr::Lis(_)) => {
-                    continue;
-                }
-                (Addr::Con(h1), Addr::Con(h2)) => match (&self.heap[h1], &self.heap[h2]) {
-                    (
-                        &HeapCellValue::Atom(ref n1, ref spec_1),
-                        &HeapCellValue::Atom(ref n2, ref spec_2),
-                    ) => {
-                        if n1 != n2 || spec_1 != spec_2 {
-                            return true;
-                        }
-                    }
-                    (&HeapCellValue::DBRef(ref db_ref_1), &HeapCellValue::DBRef(ref db_ref_2)) => {
-                        if db_ref_1 != db_ref_2 {
-                            return true;
-                        }
-                    }
-                    (v1, v2) => {
-                        if let Ok(n1) = Number::try_from(v1) {
-                            if let Ok(n2) = Number::try_from(v2) {
-                                if n1 == n2 {
-                                    continue;
-                                }
-                            }
-                        }
-
-                        return true;
-                    }
-                },
-                (Addr::Con(h), Addr::Char(c)) | (Addr::Char(c), Addr::Con(h)) => {
-                    match &self.heap[h] {
-                        &HeapCellValue::Atom(ref name, _) if name.is_char() => {
-                            if name.as_str().chars().next() != Some(c) {
-                                return true;
-                            }
-                        }
-                        _ => {
-                            return true;
-                        }
-                    }
-                }
-                (a1, a2) => {
-                    if let Ok(n1) = Number::try_from((a1, &self.heap)) {
-                        if let Ok(n2) = Number::try_from((a2, &self.heap)) {
-                            if n1 != n2 {
-                                return true;
-                            } else {
-                                continue;
-                            }
-                        }
-                    }
-
-                    if a1 != a2 {
-                        return true;
-                    }
-                }
-            }
-        }
-
-        // did the two iterators expire at the same step?
-        iter.first_to_expire != Ordering::Equal
-    }
-
     pub(super) fn compare_term_test(&self, a1: &Addr, a2: &Addr) -> Option<Ordering> {
         let mut iter = self.zipped_acyclic_pre_order_iter(*a1, *a2);
 
@@ -2093,7 +2006,9 @@ impl MachineState {
                 Some(TermOrderCategory::FloatingPoint) => {
                     if let Addr::Float(f1) = v1 {
                         if let Addr::Float(f2) = v2 {
-                            return Some(f1.cmp(&f2));
+                            if f1 != f2 {
+                                return Some(f1.cmp(&f2));
+                            }
                         } else {
                             unreachable!()
                         }
@@ -2282,7 +2197,7 @@ impl MachineState {
                     (Addr::Lis(_), Addr::Str(s)) => {
                         if let &HeapCellValue::NamedStr(a1, ref n1, _) = &self.heap[s] {
                             if a1 != 2 || n1.as_str() != "." {
-                                return Some(a1.cmp(&2).then_with(|| n1.as_str().cmp(".")));
+                                return Some(2.cmp(&a1).then_with(|| ".".cmp(n1.as_str())));
                             }
                         } else {
                             unreachable!()
@@ -2291,7 +2206,7 @@ impl MachineState {
                     (Addr::Str(s), Addr::Lis(_)) => {
                         if let &HeapCellValue::NamedStr(a1, ref n1, _) = &self.heap[s] {
                             if a1 != 2 || n1.as_str() != "." {
-                                return Some(2.cmp(&a1).then_with(|| ".".cmp(n1.as_str())));
+                                return Some(a1.cmp(&2).then_with(|| n1.as_str().cmp(".")));
                             }
                         } else {
                             unreachable!()
@@ -2300,7 +2215,7 @@ impl MachineState {
                     (Addr::PStrLocation(..), Addr::Str(s)) => {
                         if let &HeapCellValue::NamedStr(a1, ref n1, _) = &self.heap[s] {
                             if a1 != 2 || n1.as_str() != "." {
-                                return Some(a1.cmp(&2).then_with(|| n1.as_str().cmp(".")));
+                                return Some(2.cmp(&a1).then_with(|| ".".cmp(n1.as_str())));
                             }
                         } else {
                             unreachable!()
@@ -2309,7 +2224,7 @@ impl MachineState {
                     (Addr::Str(s), Addr::PStrLocation(..)) => {
                         if let &HeapCellValue::NamedStr(a1, ref n1, _) = &self.heap[s] {
                             if a1 != 2 || n1.as_str() != "." {
-                                return Some(2.cmp(&a1).then_with(|| ".".cmp(n1.as_str())));
+                                return Some(a1.cmp(&2).then_with(|| n1.as_str().cmp(".")));
                             }
                         } else {
                             unreachable!()
@@ -2319,9 +2234,22 @@ impl MachineState {
                         return None;
                     }
                 },
-                None => {
-                    return None;
-                }
+                // terms outside the standard order, such as streams and
+                // database references, are comparable only for identity.
+                None => match (v1, v2) {
+                    (Addr::Con(h1), Addr::Con(h2)) => match (&self.heap[h1], &self.heap[h2]) {
+                        (&HeapCellValue::DBRef(ref db_ref_1), &HeapCellValue::DBRef(ref db_ref_2))
+                            if db_ref_1 == db_ref_2 => {}
+                        _ => {
+                            return None;
+                        }
+                    },
+                    (v1, v2) => {
+                        if v1 != v2 {
+                            return None;
+                        }
+                    }
+                },
             }
         }
 
@@ -2828,7 +2756,7 @@ impl MachineState {
                 Err(self.error_form(MachineError::instantiation_error(), stub))
             }
             Addr::Str(s) => match self.heap.clone(s) {
-                HeapCellValue::NamedStr(2, ref name, Some(_)) if *name == clause_name!("-") => {
+                HeapCellValue::NamedStr(2, ref name, _) if *name == clause_name!("-") => {
                     Ok(Addr::HeapCell(s + 1))
                 }
                 _ => Err(self.error_form(
@@ -3083,6 +3011,8 @@ impl MachineState {
             Err(_) => unreachable!(),
         }
 
+        self.inference_count += 1;
+
         let mut default_call_policy: Box<dyn CallPolicy> = Box::new(DefaultCallPolicy {});
 
         let call_policy = if use_default_cp {
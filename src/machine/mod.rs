@@ -6,6 +6,7 @@ use lazy_static::lazy_static;
 
 use crate::clause_types::*;
 use crate::forms::*;
+use crate::heap_print::*;
 use crate::instructions::*;
 use crate::machine::heap::*;
 use crate::machine::loader::*;
@@ -30,6 +31,8 @@ mod raw_block;
 mod stack;
 pub(crate) mod streams;
 mod term_stream;
+#[cfg(test)]
+pub(crate) mod test_utils;
 
 #[macro_use]
 mod arithmetic_ops;
@@ -46,8 +49,8 @@ pub use crate::machine::streams::Stream;
 
 use indexmap::IndexMap;
 
-//use std::convert::TryFrom;
 use prolog_parser::ast::ClauseName;
+use std::convert::TryFrom;
 use std::fs::File;
 use std::mem;
 use std::path::PathBuf;
@@ -119,6 +122,16 @@ pub struct Machine {
     pub(super) load_contexts: Vec<LoadContext>,
 }
 
+/// The answers collected by `Machine::run_query_collecting`. Each
+/// answer pairs the names of the query variables with their bindings,
+/// written as by `writeq/1`. `truncated` is set when the query has
+/// more solutions than the limit allows.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct QueryResult {
+    pub answers: Vec<Vec<(String, String)>>,
+    pub truncated: bool,
+}
+
 #[inline]
 fn current_dir() -> PathBuf {
     std::env::current_dir().unwrap_or(PathBuf::from("./"))
@@ -219,6 +232,160 @@ impl Machine {
         self.run_module_predicate(clause_name!("$toplevel"), (clause_name!("$repl"), 1));
     }
 
+    /// Runs `query` in the user module and collects its answers,
+    /// stopping after `max_solutions` of them if a limit is given. The
+    /// query is run to completion or cut at the limit before this
+    /// returns, so the machine can be used for further queries.
+    /// Syntax errors and uncaught exceptions are returned as `Err`,
+    /// written as by `writeq/1`.
+    pub fn run_query_collecting(
+        &mut self,
+        query: &str,
+        max_solutions: Option<usize>,
+    ) -> Result<QueryResult, String> {
+        let mut query = query.trim_end().to_string();
+
+        if !query.ends_with('.') {
+            query.push('.');
+        }
+
+        let term_write_result = match self.machine_st.read(
+            Stream::from(query),
+            self.machine_st.atom_tbl.clone(),
+            &self.indices.op_dir,
+        ) {
+            Ok(term_write_result) => term_write_result,
+            Err(err) => return Err(format!("syntax_error({})", err.as_str())),
+        };
+
+        let read_term_vars = self.machine_st.read_term_vars(
+            &term_write_result,
+            ReadTermOptions {
+                variable_names: true,
+                ..ReadTermOptions::default()
+            },
+            &self.indices.op_dir,
+        );
+
+        let var_list = read_term_vars.variable_names.unwrap();
+
+        let max = match max_solutions.map(isize::try_from) {
+            Some(Ok(n)) => Addr::Fixnum(n),
+            _ => Addr::Con(
+                self.machine_st
+                    .heap
+                    .push(HeapCellValue::Atom(clause_name!("inf"), None)),
+            ),
+        };
+
+        let answers = self.machine_st.heap.h();
+        self.machine_st
+            .heap
+            .push(HeapCellValue::Addr(Addr::HeapCell(answers)));
+
+        let outcome = self.machine_st.heap.h();
+        self.machine_st
+            .heap
+            .push(HeapCellValue::Addr(Addr::HeapCell(outcome)));
+
+        self.machine_st[temp_v!(1)] = Addr::HeapCell(term_write_result.heap_loc);
+        self.machine_st[temp_v!(2)] = var_list;
+        self.machine_st[temp_v!(3)] = max;
+        self.machine_st[temp_v!(4)] = Addr::HeapCell(answers);
+        self.machine_st[temp_v!(5)] = Addr::HeapCell(outcome);
+
+        self.run_module_predicate(
+            clause_name!("$toplevel"),
+            (clause_name!("$collect_answers"), 5),
+        );
+
+        let outcome = self
+            .machine_st
+            .store(self.machine_st.deref(Addr::HeapCell(outcome)));
+
+        // the answers are built by '$collect_answers'/5, so anything
+        // unexpected in them is reported rather than trusted.
+        let malformed = |what: &str| format!("run_query_collecting: malformed {}", what);
+
+        let truncated = match outcome {
+            Addr::Con(h) => match &self.machine_st.heap[h] {
+                HeapCellValue::Atom(ref name, _) => name.as_str() == "truncated",
+                _ => return Err(malformed("outcome")),
+            },
+            Addr::Str(s) => {
+                return Err(self.print_answer_term(Addr::HeapCell(s + 1), IndexMap::new()));
+            }
+            _ => return Err(malformed("outcome")),
+        };
+
+        self.machine_st[temp_v!(1)] = Addr::HeapCell(answers);
+
+        let stub = || MachineError::functor_stub(clause_name!("run_query_collecting"), 2);
+        let answer_addrs = self
+            .machine_st
+            .try_from_list(temp_v!(1), stub())
+            .map_err(|_| malformed("answer list"))?;
+
+        let mut result = QueryResult {
+            answers: Vec::with_capacity(answer_addrs.len()),
+            truncated,
+        };
+
+        for answer_addr in answer_addrs {
+            self.machine_st[temp_v!(1)] = answer_addr;
+
+            let mut var_names = IndexMap::new();
+            let mut bindings = vec![];
+
+            let var_eqs = self
+                .machine_st
+                .try_from_list(temp_v!(1), stub())
+                .map_err(|_| malformed("answer"))?;
+
+            for var_eq in var_eqs {
+                if let Addr::Str(s) = self.machine_st.store(self.machine_st.deref(var_eq)) {
+                    let name = match &self.machine_st.heap[s + 1] {
+                        HeapCellValue::Atom(ref name, _) => name.as_str().to_string(),
+                        _ => return Err(malformed("variable name")),
+                    };
+
+                    let value = self
+                        .machine_st
+                        .store(self.machine_st.deref(Addr::HeapCell(s + 2)));
+
+                    if value.is_ref() && !var_names.contains_key(&value) {
+                        var_names.insert(value, name.clone());
+                    }
+
+                    bindings.push((name, value));
+                }
+            }
+
+            let answer = bindings
+                .into_iter()
+                .map(|(name, value)| (name, self.print_answer_term(value, var_names.clone())))
+                .collect();
+
+            result.answers.push(answer);
+        }
+
+        Ok(result)
+    }
+
+    fn print_answer_term(&self, addr: Addr, var_names: IndexMap<Addr, Var>) -> String {
+        let mut printer = HCPrinter::new(
+            &self.machine_st,
+            &self.indices.op_dir,
+            PrinterOutputter::new(),
+        );
+
+        printer.quoted = true;
+        printer.numbervars = true;
+        printer.var_names = var_names;
+
+        printer.print(addr).result()
+    }
+
     pub(crate) fn configure_modules(&mut self) {
         fn update_call_n_indices(loader: &Module, target_code_dir: &mut CodeDir) {
             for arity in 1..66 {
@@ -320,6 +487,13 @@ impl Machine {
                 &CompilationTarget::User,
                 builtins,
             );
+
+            if let Some(code_index) = builtins
+                .code_dir
+                .get(&(clause_name!("$warn_unknown_procedure"), 1))
+            {
+                wam.machine_st.unknown_warning_loc = code_index.local().unwrap();
+            }
         } else {
             unreachable!()
         }
@@ -481,6 +655,9 @@ impl Machine {
             REPLCodePtr::AbolishClause => {
                 self.abolish_clause();
             }
+            REPLCodePtr::DeclareDynamicPredicate => {
+                self.declare_dynamic_predicate();
+            }
             REPLCodePtr::IsConsistentWithTermQueue => {
                 self.is_consistent_with_term_queue();
             }
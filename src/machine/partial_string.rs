@@ -235,6 +235,43 @@ impl<'a> HeapPStrIter<'a> {
 
         buf
     }
+
+    // returns the n-th character (counting from 0) of the string,
+    // stepping over partial string segments without visiting the
+    // heap once per character.
+    pub(crate) fn nth_char(&mut self, mut n: usize) -> Option<char> {
+        while let Some(iteratee) = self.next() {
+            match iteratee {
+                PStrIteratee::Char(c) => {
+                    if n == 0 {
+                        return Some(c);
+                    }
+
+                    n -= 1;
+                }
+                PStrIteratee::PStrSegment(h, offset) => match &self.machine_st.heap[h] {
+                    HeapCellValue::PartialString(ref pstr, _) => {
+                        let mut count = 0;
+
+                        for c in pstr.as_str_from(offset).chars() {
+                            if count == n {
+                                return Some(c);
+                            }
+
+                            count += 1;
+                        }
+
+                        n -= count;
+                    }
+                    _ => {
+                        unreachable!()
+                    }
+                },
+            }
+        }
+
+        None
+    }
 }
 
 #[derive(Debug, Clone, Copy)]
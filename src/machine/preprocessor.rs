@@ -63,21 +63,19 @@ pub(crate) fn to_op_decl(
     }
 }
 
-fn setup_op_decl(
+fn setup_op_name(term: Term, atom_tbl: TabledData<Atom>) -> Result<ClauseName, CompilationError> {
+    match term {
+        Term::Constant(_, Constant::Atom(name, _)) => Ok(name),
+        Term::Constant(_, Constant::Char(c)) => Ok(clause_name!(c.to_string(), atom_tbl)),
+        _ => Err(CompilationError::InconsistentEntry),
+    }
+}
+
+fn setup_op_prec_and_spec(
     mut terms: Vec<Box<Term>>,
     atom_tbl: TabledData<Atom>,
-) -> Result<OpDecl, CompilationError> {
-    let name = match *terms.pop().unwrap() {
-        Term::Constant(_, Constant::Atom(name, _)) => name,
-        Term::Constant(_, Constant::Char(c)) => clause_name!(c.to_string(), atom_tbl),
-        _ => return Err(CompilationError::InconsistentEntry),
-    };
-
-    let spec = match *terms.pop().unwrap() {
-        Term::Constant(_, Constant::Atom(name, _)) => name,
-        Term::Constant(_, Constant::Char(c)) => clause_name!(c.to_string(), atom_tbl),
-        _ => return Err(CompilationError::InconsistentEntry),
-    };
+) -> Result<(usize, ClauseName), CompilationError> {
+    let spec = setup_op_name(*terms.pop().unwrap(), atom_tbl)?;
 
     let prec = match *terms.pop().unwrap() {
         Term::Constant(_, Constant::Fixnum(bi)) => match usize::try_from(bi) {
@@ -87,9 +85,52 @@ fn setup_op_decl(
         _ => return Err(CompilationError::InconsistentEntry),
     };
 
+    Ok((prec, spec))
+}
+
+fn setup_op_decl(
+    mut terms: Vec<Box<Term>>,
+    atom_tbl: TabledData<Atom>,
+) -> Result<OpDecl, CompilationError> {
+    let name = setup_op_name(*terms.pop().unwrap(), atom_tbl.clone())?;
+    let (prec, spec) = setup_op_prec_and_spec(terms, atom_tbl)?;
+
     to_op_decl(prec, spec.as_str(), name)
 }
 
+// like setup_op_decl, but a list of names declares an operator for
+// each of them.
+fn setup_op_decls(
+    mut terms: Vec<Box<Term>>,
+    atom_tbl: TabledData<Atom>,
+) -> Result<Vec<OpDecl>, CompilationError> {
+    let mut names = vec![];
+    let mut name_list = *terms.pop().unwrap();
+
+    match name_list {
+        Term::Cons(..) | Term::Constant(_, Constant::EmptyList) => {
+            while let Term::Cons(_, t1, t2) = name_list {
+                names.push(setup_op_name(*t1, atom_tbl.clone())?);
+                name_list = *t2;
+            }
+
+            if !matches!(name_list, Term::Constant(_, Constant::EmptyList)) {
+                return Err(CompilationError::InconsistentEntry);
+            }
+        }
+        name => {
+            names.push(setup_op_name(name, atom_tbl.clone())?);
+        }
+    }
+
+    let (prec, spec) = setup_op_prec_and_spec(terms, atom_tbl)?;
+
+    names
+        .into_iter()
+        .map(|name| to_op_decl(prec, spec.as_str(), name))
+        .collect()
+}
+
 fn setup_predicate_indicator(term: &mut Term) -> Result<PredicateKey, CompilationError> {
     match term {
         Term::Clause(_, ref slash, ref mut terms, Some(_))
@@ -508,7 +549,7 @@ pub(super) fn setup_declaration<'a>(
                 Ok(Declaration::Dynamic(name, arity))
             }
             ("module", 2) => Ok(Declaration::Module(setup_module_decl(terms, atom_tbl)?)),
-            ("op", 3) => Ok(Declaration::Op(setup_op_decl(terms, atom_tbl)?)),
+            ("op", 3) => Ok(Declaration::Op(setup_op_decls(terms, atom_tbl)?)),
             ("non_counted_backtracking", 1) => {
                 let (name, arity) = setup_predicate_indicator(&mut *terms.pop().unwrap())?;
                 Ok(Declaration::NonCountedBacktracking(name, arity))
@@ -711,6 +752,19 @@ impl Preprocessor {
 
                     Ok(QueryTerm::Jump(stub))
                 }
+                ("once", 1) => {
+                    // once(G) is compiled as (G -> true), committing to
+                    // the first solution of G by a cut in the fabricated
+                    // if-then rule.
+                    let prec = *terms.pop().unwrap();
+                    let conq =
+                        Term::Constant(Cell::default(), Constant::Atom(clause_name!("true"), None));
+
+                    let (stub, clauses) = self.fabricate_if_then(prec, conq);
+                    self.queue.push_back(clauses);
+
+                    Ok(QueryTerm::Jump(stub))
+                }
                 ("$get_level", 1) => {
                     if let Term::Var(_, ref var) = *terms[0] {
                         Ok(QueryTerm::GetLevelAndUnify(Cell::default(), var.clone()))
@@ -91,12 +91,19 @@ copy_attribute_modules([Module:_|Attrs], [Module|Modules0], Modules1) :-
     copy_attribute_modules(Attrs, Modules0, Modules1).
 
 
+% attribute_goals//1 may modify the attributes it describes, so the
+% goals are gathered under findall/3, leaving Source untouched.
 copy_term(Source, Dest, Goals) :-
+    findall([Source | Goals0],
+            '$project_atts':source_attribute_goals(Source, Goals0),
+            [Copy]),
+    '$copy_term_without_attr_vars'(Copy, [Dest | Goals]).
+
+source_attribute_goals(Source, Goals) :-
     '$term_attributed_variables'(Source, AttrVars),
     gather_attr_modules(AttrVars, Modules0),
     sort(Modules0, Modules),
     call_attribute_goals_with_module_prefix(Modules, '$project_atts':call_query_var_goals,
                                             AttrVars, Goals0),
-    sort(Goals0, Goals1),
-    !,
-    '$copy_term_without_attr_vars'([Source | Goals1], [Dest | Goals]).
+    sort(Goals0, Goals),
+    !.
@@ -75,7 +75,7 @@ impl<T: RawBlockTraits> RawBlock<T> {
     }
 
     #[inline]
-    fn free_space(&self) -> usize {
+    pub(crate) fn free_space(&self) -> usize {
         debug_assert!(
             self.top >= self.base,
             "self.top = {:?} < {:?} = self.base",
@@ -167,6 +167,19 @@ impl Stack {
         }
     }
 
+    // the number of cells occupied by frames, preludes included.
+    #[inline]
+    pub(crate) fn used_cells(&self) -> usize {
+        let base = StackTraits::base_offset(self.buf.base) as usize;
+        (self.buf.top as usize - base) / mem::size_of::<Addr>()
+    }
+
+    // the number of cells that fit in the stack before it must grow.
+    #[inline]
+    pub(crate) fn free_cells(&self) -> usize {
+        self.buf.free_space() / mem::size_of::<Addr>()
+    }
+
     pub(crate) fn allocate_and_frame(&mut self, num_cells: usize) -> usize {
         let frame_size = AndFrame::size_of(num_cells);
 
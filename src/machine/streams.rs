@@ -729,6 +729,26 @@ impl Stream {
         use unicode_reader::CodePoints;
 
         match self.stream_inst.0.borrow_mut().stream_inst {
+            StreamInstance::Bytes(ref mut cursor) => {
+                let pos = cursor.position();
+
+                let c = {
+                    let mut iter = CodePoints::from(&mut *cursor);
+
+                    if let Some(Ok(c)) = iter.next() {
+                        c
+                    } else {
+                        return Err(std::io::Error::new(
+                            ErrorKind::UnexpectedEof,
+                            StreamError::PeekCharFailed,
+                        ));
+                    }
+                };
+
+                cursor.set_position(pos);
+
+                Ok(c)
+            }
             StreamInstance::InputFile(_, ref mut file) => {
                 let c = {
                     let mut iter = CodePoints::from(&*file);
@@ -1,10 +1,11 @@
 use prolog_parser::ast::*;
 use prolog_parser::parser::*;
 use prolog_parser::{
-    alpha_char, alpha_numeric_char, binary_digit_char, clause_name, decimal_digit_char,
-    exponent_char, graphic_char, graphic_token_char, hexadecimal_digit_char, layout_char,
-    meta_char, new_line_char, octal_digit_char, octet_char, prolog_char, sign_char, solo_char,
-    symbolic_control_char, symbolic_hexadecimal_char, temp_v,
+    alpha_char, alpha_numeric_char, backslash_char, binary_digit_char, clause_name,
+    decimal_digit_char, decimal_point_char, exponent_char, graphic_char, graphic_token_char,
+    hexadecimal_digit_char, layout_char, meta_char, new_line_char, octal_digit_char, octet_char,
+    prolog_char, sign_char, single_quote_char, solo_char, symbolic_control_char,
+    symbolic_hexadecimal_char, temp_v,
 };
 
 use lazy_static::lazy_static;
@@ -23,14 +24,15 @@ use crate::machine::machine_state::*;
 use crate::machine::preprocessor::to_op_decl;
 use crate::machine::streams::*;
 
-use crate::read::readline;
-use crate::rug::Integer;
+use crate::read::{readline, write_layout_to_heap, ReadTermOptions};
+use crate::rug::{Integer, Rational};
 use ordered_float::OrderedFloat;
 
-use indexmap::IndexSet;
+use indexmap::{IndexMap, IndexSet};
 
 use ref_thread_local::RefThreadLocal;
 
+use std::cmp::Ordering;
 use std::collections::BTreeSet;
 use std::convert::TryFrom;
 use std::env;
@@ -39,7 +41,7 @@ use std::io::{ErrorKind, Read, Write};
 use std::iter::{once, FromIterator};
 use std::net::{TcpListener, TcpStream};
 use std::num::NonZeroU32;
-use std::ops::Sub;
+use std::ops::{Range, Sub};
 use std::rc::Rc;
 
 use chrono::{offset::Local, DateTime};
@@ -141,6 +143,176 @@ impl BrentAlgState {
     }
 }
 
+// the mixing step of FxHash. unlike the std hashers, its output
+// doesn't depend on a per-process seed, so term_hash/2 is stable
+// across runs.
+#[derive(Debug, Default)]
+struct TermHasher {
+    hash: u64,
+}
+
+impl TermHasher {
+    const SEED: u64 = 0x51_7c_c1_b7_27_22_0a_95;
+
+    #[inline]
+    fn write_u64(&mut self, word: u64) {
+        self.hash = (self.hash.rotate_left(5) ^ word).wrapping_mul(Self::SEED);
+    }
+
+    fn write_str(&mut self, tag: u8, s: &str) {
+        self.write_u64(tag as u64);
+
+        for b in s.bytes() {
+            self.write_u64(b as u64);
+        }
+
+        self.write_u64(s.len() as u64);
+    }
+
+    #[inline]
+    fn write_functor(&mut self, name: &str, arity: usize) {
+        self.write_str(0, name);
+        self.write_u64(arity as u64);
+    }
+}
+
+// scans the longest prefix of a character stream that lexes as a
+// number. characters looked at but not part of the number are kept in
+// put_back, the next to be read last, so they can be returned to the
+// stream.
+struct NumberLexer<'a, I: Iterator<Item = std::io::Result<char>>> {
+    iter: &'a mut I,
+    put_back: Vec<char>,
+    token: String,
+}
+
+impl<'a, I: Iterator<Item = std::io::Result<char>>> NumberLexer<'a, I> {
+    fn new(iter: &'a mut I) -> Self {
+        NumberLexer {
+            iter,
+            put_back: vec![],
+            token: String::new(),
+        }
+    }
+
+    fn next_char(&mut self) -> Option<char> {
+        match self.put_back.pop() {
+            Some(c) => Some(c),
+            None => match self.iter.next() {
+                Some(Ok(c)) => Some(c),
+                _ => None,
+            },
+        }
+    }
+
+    fn lookahead_char(&mut self) -> Option<char> {
+        let c = self.next_char()?;
+        self.put_back.push(c);
+        Some(c)
+    }
+
+    fn accept(&mut self, pred: impl Fn(char) -> bool) -> bool {
+        match self.next_char() {
+            Some(c) if pred(c) => {
+                self.token.push(c);
+                true
+            }
+            Some(c) => {
+                self.put_back.push(c);
+                false
+            }
+            None => false,
+        }
+    }
+
+    fn accept_all(&mut self, pred: impl Fn(char) -> bool) -> bool {
+        let mut accepted = false;
+
+        while self.accept(&pred) {
+            accepted = true;
+        }
+
+        accepted
+    }
+
+    fn unread(&mut self, n: usize) {
+        for _ in 0..n {
+            if let Some(c) = self.token.pop() {
+                self.put_back.push(c);
+            }
+        }
+    }
+
+    fn char_code(&mut self) -> bool {
+        self.accept(|c| single_quote_char!(c));
+
+        if self.accept(|c| !(layout_char!(c) || backslash_char!(c) || single_quote_char!(c))) {
+            return true;
+        }
+
+        if self.accept(|c| single_quote_char!(c)) {
+            if self.accept(|c| single_quote_char!(c)) {
+                return true;
+            }
+
+            self.unread(1);
+        }
+
+        self.unread(1);
+        false
+    }
+
+    fn radix_digits(&mut self) -> bool {
+        let digit: fn(char) -> bool = match self.lookahead_char() {
+            Some('x') => |c| hexadecimal_digit_char!(c),
+            Some('o') => |c| octal_digit_char!(c),
+            Some('b') => |c| binary_digit_char!(c),
+            Some('\'') => return self.char_code(),
+            _ => return false,
+        };
+
+        self.accept(|_| true);
+
+        if self.accept_all(digit) {
+            true
+        } else {
+            self.unread(1);
+            false
+        }
+    }
+
+    // returns false, leaving the token empty, if the stream doesn't
+    // start with a number.
+    fn scan(&mut self) -> bool {
+        if !self.accept_all(|c| decimal_digit_char!(c)) {
+            return false;
+        }
+
+        if self.token == "0" && self.radix_digits() {
+            return true;
+        }
+
+        if self.accept(|c| decimal_point_char!(c)) {
+            if !self.accept_all(|c| decimal_digit_char!(c)) {
+                self.unread(1);
+                return true;
+            }
+
+            let mantissa_len = self.token.len();
+
+            if self.accept(|c| exponent_char!(c)) {
+                self.accept(|c| sign_char!(c));
+
+                if !self.accept_all(|c| decimal_digit_char!(c)) {
+                    self.unread(self.token.len() - mantissa_len);
+                }
+            }
+        }
+
+        true
+    }
+}
+
 fn is_builtin_predicate(name: &ClauseName) -> bool {
     let in_builtins = name.owning_module().as_str() == "builtins";
     let hidden_name = name.as_str().starts_with("$");
@@ -448,18 +620,235 @@ impl MachineState {
     fn copy_findall_solution(&mut self, lh_offset: usize, copy_target: Addr) -> usize {
         let threshold = self.lifted_heap.h() - lh_offset;
 
-        let mut copy_ball_term =
-            CopyBallTerm::new(&mut self.stack, &mut self.heap, &mut self.lifted_heap);
+        self.lifted_heap
+            .push(HeapCellValue::Addr(Addr::Lis(threshold + 1)));
+        self.lifted_heap
+            .push(HeapCellValue::Addr(Addr::HeapCell(threshold + 3)));
+        self.lifted_heap
+            .push(HeapCellValue::Addr(Addr::HeapCell(threshold + 2)));
+
+        let cache_index = self.copy_ground_subterms_to_lh(lh_offset, copy_target);
+        let solution = self.lifted_heap.h() - lh_offset;
+
+        self.lifted_heap[threshold + lh_offset + 1] = HeapCellValue::Addr(Addr::HeapCell(solution));
+
+        let shared_copies = match cache_index {
+            Some(n) => Some(&self.lh_ground_caches[n].copies),
+            None => None,
+        };
 
-        copy_ball_term.push(HeapCellValue::Addr(Addr::Lis(threshold + 1)));
-        copy_ball_term.push(HeapCellValue::Addr(Addr::HeapCell(threshold + 3)));
-        copy_ball_term.push(HeapCellValue::Addr(Addr::HeapCell(threshold + 2)));
+        let copy_ball_term =
+            CopyBallTerm::new(&mut self.stack, &mut self.heap, &mut self.lifted_heap)
+                .with_shared_copies(shared_copies);
 
         copy_term(copy_ball_term, copy_target, AttrVarPolicy::DeepCopy);
 
         threshold + lh_offset + 2
     }
 
+    // copies the ground subterms of the template that aren't yet in
+    // the ground cache of the findall/3 call collecting at lh_offset
+    // and records their locations. returns the index of the cache, if
+    // there is one.
+    fn copy_ground_subterms_to_lh(&mut self, lh_offset: usize, template: Addr) -> Option<usize> {
+        let n = self.lh_ground_caches.len().checked_sub(1)?;
+
+        if self.lh_ground_caches[n].lh_offset != lh_offset {
+            return None;
+        }
+
+        for addr in self.unshared_ground_subterms(template, &self.lh_ground_caches[n]) {
+            let h = self.lifted_heap.h();
+
+            let copy_ball_term =
+                CopyBallTerm::new(&mut self.stack, &mut self.heap, &mut self.lifted_heap)
+                    .with_shared_copies(Some(&self.lh_ground_caches[n].copies));
+
+            copy_term(copy_ball_term, addr, AttrVarPolicy::DeepCopy);
+
+            match self.lifted_heap[h] {
+                HeapCellValue::Addr(Addr::Lis(l)) | HeapCellValue::Addr(Addr::Str(l)) => {
+                    let lh_index = l - self.heap.h();
+                    self.lh_ground_caches[n].copies.insert(addr, lh_index);
+                }
+                _ => {}
+            }
+        }
+
+        Some(n)
+    }
+
+    fn compound_arg_cells(&self, addr: Addr) -> Option<Range<usize>> {
+        match addr {
+            Addr::Lis(l) => Some(l..l + 2),
+            Addr::Str(s) => match &self.heap[s] {
+                &HeapCellValue::NamedStr(arity, ..) => Some(s + 1..s + 1 + arity),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+
+    // the maximal compound subterms of the template that are ground,
+    // were built below the heap boundary of the cache and haven't
+    // been bound since, less those the cache has already copied.
+    fn unshared_ground_subterms(&self, template: Addr, cache: &LiftedHeapGroundCache) -> Vec<Addr> {
+        let is_compound = |addr: &Addr| match addr {
+            Addr::Lis(_) | Addr::Str(_) => true,
+            _ => false,
+        };
+
+        let root = self.store(self.deref(template));
+
+        if !is_compound(&root) {
+            return vec![];
+        }
+
+        // templates holding no compound built below the heap boundary
+        // have nothing to share, so the trail isn't scanned for them.
+        let mut visited = IndexSet::new();
+        let mut stack = vec![root];
+        let mut reaches_boundary = false;
+
+        while let Some(addr) = stack.pop() {
+            match addr {
+                Addr::Lis(h) | Addr::Str(h) if h < cache.heap_boundary => {
+                    reaches_boundary = true;
+                    break;
+                }
+                _ => {}
+            }
+
+            if !visited.insert(addr) {
+                continue;
+            }
+
+            if let Some(cells) = self.compound_arg_cells(addr) {
+                for h in cells {
+                    let arg = self.store(self.deref(Addr::HeapCell(h)));
+
+                    if is_compound(&arg) {
+                        stack.push(arg);
+                    }
+                }
+            }
+        }
+
+        if !reaches_boundary {
+            return vec![];
+        }
+
+        let tr = cache.trail_boundary.min(self.tr);
+
+        let trailed: IndexSet<usize> = self.trail[tr..self.tr]
+            .iter()
+            .filter_map(|r| match r {
+                &TrailRef::Ref(Ref::HeapCell(h))
+                | &TrailRef::Ref(Ref::AttrVar(h))
+                | &TrailRef::AttrVarHeapLink(h)
+                | &TrailRef::AttrVarListLink(h, _) => Some(h),
+                _ => None,
+            })
+            .collect();
+
+        // the value of the heap cell h, provided h and every cell on
+        // its reference chain belong to the ground prefix of the heap.
+        let stable_value = |mut h: usize| -> Option<Addr> {
+            loop {
+                if h >= cache.heap_boundary || trailed.contains(&h) {
+                    return None;
+                }
+
+                match self.heap[h].as_addr(h) {
+                    Addr::HeapCell(r) if r != h => h = r,
+                    Addr::Con(c) if c < cache.heap_boundary => return Some(Addr::Con(c)),
+                    addr @ Addr::Lis(_)
+                    | addr @ Addr::Str(_)
+                    | addr @ Addr::Char(_)
+                    | addr @ Addr::EmptyList
+                    | addr @ Addr::Fixnum(_)
+                    | addr @ Addr::Float(_)
+                    | addr @ Addr::Usize(_) => return Some(addr),
+                    _ => return None,
+                }
+            }
+        };
+
+        let mut ground = IndexMap::new();
+        let mut stack = vec![(root, false)];
+
+        while let Some((addr, expanded)) = stack.pop() {
+            let mut cells = match self.compound_arg_cells(addr) {
+                Some(cells) => cells,
+                None => continue,
+            };
+
+            if expanded {
+                let is_ground = match addr {
+                    Addr::Lis(h) | Addr::Str(h) => h < cache.heap_boundary,
+                    _ => false,
+                } && cells.all(|h| match stable_value(h) {
+                    Some(arg) if is_compound(&arg) => ground.get(&arg) == Some(&true),
+                    Some(_) => true,
+                    None => false,
+                });
+
+                ground.insert(addr, is_ground);
+            } else if !ground.contains_key(&addr) {
+                if cache.copies.contains_key(&addr) {
+                    ground.insert(addr, true);
+                    continue;
+                }
+
+                // cyclic terms are left unshared.
+                ground.insert(addr, false);
+                stack.push((addr, true));
+
+                for h in cells {
+                    let arg = self.store(self.deref(Addr::HeapCell(h)));
+
+                    if is_compound(&arg) && !ground.contains_key(&arg) {
+                        stack.push((arg, false));
+                    }
+                }
+            }
+        }
+
+        let mut subterms = vec![];
+        let mut visited = IndexSet::new();
+        let mut stack = vec![root];
+
+        while let Some(addr) = stack.pop() {
+            if !visited.insert(addr) || cache.copies.contains_key(&addr) {
+                continue;
+            }
+
+            if ground.get(&addr) == Some(&true) {
+                subterms.push(addr);
+                continue;
+            }
+
+            if let Some(cells) = self.compound_arg_cells(addr) {
+                for h in cells {
+                    let arg = self.store(self.deref(Addr::HeapCell(h)));
+
+                    if is_compound(&arg) {
+                        stack.push(arg);
+                    }
+                }
+            }
+        }
+
+        subterms
+    }
+
+    #[inline]
+    fn truncate_lifted_heap(&mut self, lh_offset: usize) {
+        self.lifted_heap.truncate(lh_offset);
+        self.lh_ground_caches
+            .retain(|cache| cache.lh_offset < lh_offset);
+    }
+
     fn repl_redirect(&mut self, repl_code_ptr: REPLCodePtr) -> CallResult {
         let p = if self.last_call {
             self.cp
@@ -477,7 +866,7 @@ impl MachineState {
         match self.store(self.deref(self[temp_v!(1)])) {
             Addr::Usize(lh_offset) => {
                 if lh_offset >= self.lifted_heap.h() {
-                    self.lifted_heap.truncate(lh_offset);
+                    self.truncate_lifted_heap(lh_offset);
                 } else {
                     let threshold = self.lifted_heap.h() - lh_offset;
                     self.lifted_heap
@@ -495,12 +884,7 @@ impl MachineState {
                 let mut iter = indices.code_dir.range(key..).skip(1);
 
                 while let Some(((name, arity), idx)) = iter.next() {
-                    if idx.is_undefined() {
-                        self.fail = true;
-                        return;
-                    }
-
-                    if is_builtin_predicate(&name) {
+                    if idx.is_undefined() || is_builtin_predicate(&name) {
                         continue;
                     }
 
@@ -631,6 +1015,46 @@ impl MachineState {
                 let addr = self.heap.put_constant(Constant::Rational(n));
                 (self.unify_fn)(self, nx, addr);
             }
+            Ok(Term::Clause(_, ref name, ref terms, _))
+                if name.as_str() == "rdiv" && terms.len() == 2 =>
+            {
+                // rationals are written as N rdiv D by number_chars/2
+                // and number_codes/2, so they're read back the same way.
+                fn integer_term(term: &Term) -> Option<Integer> {
+                    match term {
+                        Term::Constant(_, Constant::Fixnum(n)) => Some(Integer::from(*n)),
+                        Term::Constant(_, Constant::Integer(n)) => Some(Integer::from(&**n)),
+                        _ => None,
+                    }
+                }
+
+                match (integer_term(&terms[0]), integer_term(&terms[1])) {
+                    (Some(n), Some(d)) if d != 0 => {
+                        let r = Rational::from(n) / Rational::from(d);
+
+                        let addr = if *r.denom() == 1 {
+                            self.heap
+                                .put_constant(Constant::Integer(Rc::new(r.numer().clone())))
+                        } else {
+                            self.heap.put_constant(Constant::Rational(Rc::new(r)))
+                        };
+
+                        (self.unify_fn)(self, nx, addr);
+                    }
+                    (Some(_), Some(_)) => {
+                        let err = MachineError::evaluation_error(EvalError::ZeroDivisor);
+                        return Err(self.error_form(err, stub));
+                    }
+                    _ => {
+                        let err = ParserError::ParseBigInt(0, 0);
+
+                        let h = self.heap.h();
+                        let err = MachineError::syntax_error(h, err);
+
+                        return Err(self.error_form(err, stub));
+                    }
+                }
+            }
             Ok(Term::Constant(_, Constant::Float(n))) => {
                 let addr = self.heap.put_constant(Constant::Float(n));
                 (self.unify_fn)(self, nx, addr);
@@ -656,6 +1080,220 @@ impl MachineState {
         Ok(())
     }
 
+    // reads an integer argument of a name/arity predicate, returning
+    // None if addr is unbound.
+    fn integer_arg(
+        &self,
+        addr: Addr,
+        name: &'static str,
+        arity: usize,
+    ) -> Result<Option<Integer>, MachineStub> {
+        let addr = self.store(self.deref(addr));
+
+        if addr.is_ref() {
+            return Ok(None);
+        }
+
+        match Number::try_from((addr, &self.heap)) {
+            Ok(Number::Fixnum(n)) => Ok(Some(Integer::from(n))),
+            Ok(Number::Integer(n)) => Ok(Some(Integer::from(&*n))),
+            _ => {
+                let stub = MachineError::functor_stub(clause_name!(name), arity);
+                let err = MachineError::type_error(self.heap.h(), ValidType::Integer, addr);
+
+                Err(self.error_form(err, stub))
+            }
+        }
+    }
+
+    fn not_less_than_zero_arg(
+        &self,
+        addr: Addr,
+        name: &'static str,
+        arity: usize,
+    ) -> Result<Option<Integer>, MachineStub> {
+        match self.integer_arg(addr, name, arity)? {
+            Some(n) if n < 0 => {
+                let addr = self.store(self.deref(addr));
+
+                let stub = MachineError::functor_stub(clause_name!(name), arity);
+                let err = MachineError::type_error(self.heap.h(), ValidType::NotLessThanZero, addr);
+
+                Err(self.error_form(err, stub))
+            }
+            n => Ok(n),
+        }
+    }
+
+    #[inline]
+    fn put_integer(&mut self, n: Integer) -> Addr {
+        match n.to_isize() {
+            Some(n) => Addr::Fixnum(n),
+            None => self.heap.put_constant(Constant::Integer(Rc::new(n))),
+        }
+    }
+
+    // collects the characters of the string at addr, which may mix
+    // partial string segments with list cells holding chars.
+    fn string_from_chars(&self, addr: Addr, name: &'static str) -> Result<String, MachineStub> {
+        let mut iter = self.heap_pstr_iter(addr);
+        let string = iter.to_string();
+
+        let stub = MachineError::functor_stub(clause_name!(name), 2);
+
+        match iter.focus() {
+            Addr::EmptyList => Ok(string),
+            Addr::Lis(l) => {
+                let c = self.store(self.deref(Addr::HeapCell(l)));
+
+                let err = if c.is_ref() {
+                    MachineError::instantiation_error()
+                } else {
+                    MachineError::type_error(self.heap.h(), ValidType::Character, c)
+                };
+
+                Err(self.error_form(err, stub))
+            }
+            focus if focus.is_ref() => {
+                Err(self.error_form(MachineError::instantiation_error(), stub))
+            }
+            _ => {
+                let err = MachineError::type_error(self.heap.h(), ValidType::List, addr);
+                Err(self.error_form(err, stub))
+            }
+        }
+    }
+
+    // collects the characters of the list of codes in register r.
+    fn string_from_codes(&self, r: RegType, name: &'static str) -> Result<String, MachineStub> {
+        let stub = MachineError::functor_stub(clause_name!(name), 2);
+        let addrs = self.try_from_list(r, stub)?;
+
+        let mut string = String::new();
+
+        for addr in addrs {
+            let addr = self.store(self.deref(addr));
+
+            let c = match Number::try_from((addr, &self.heap)) {
+                Ok(Number::Fixnum(n)) => self.int_to_char(&Integer::from(n), name, 2)?,
+                Ok(Number::Integer(n)) => self.int_to_char(&n, name, 2)?,
+                _ => {
+                    let stub = MachineError::functor_stub(clause_name!(name), 2);
+
+                    let err = if addr.is_ref() {
+                        MachineError::instantiation_error()
+                    } else {
+                        MachineError::type_error(self.heap.h(), ValidType::Integer, addr)
+                    };
+
+                    return Err(self.error_form(err, stub));
+                }
+            };
+
+            string.push(c);
+        }
+
+        Ok(string)
+    }
+
+    // a structural hash of the ground, acyclic term at addr. atoms are
+    // hashed by name and numbers by value, and list cells hash alike
+    // whether they are stored as partial strings or not.
+    fn term_hash(&self, addr: Addr) -> u64 {
+        let mut hasher = TermHasher::default();
+
+        for addr in self.pre_order_iter(addr) {
+            match addr {
+                Addr::Lis(_) | Addr::PStrLocation(..) => {
+                    hasher.write_functor(".", 2);
+                }
+                Addr::Str(s) => {
+                    if let HeapCellValue::NamedStr(arity, ref name, _) = &self.heap[s] {
+                        hasher.write_functor(name.as_str(), *arity);
+                    } else {
+                        unreachable!()
+                    }
+                }
+                Addr::Char(c) => {
+                    let mut buf = [0u8; 4];
+                    hasher.write_functor(c.encode_utf8(&mut buf), 0);
+                }
+                Addr::EmptyList => {
+                    hasher.write_functor("[]", 0);
+                }
+                Addr::Con(h) if self.heap.atom_at(h) => {
+                    if let HeapCellValue::Atom(ref name, _) = &self.heap[h] {
+                        hasher.write_functor(name.as_str(), 0);
+                    } else {
+                        unreachable!()
+                    }
+                }
+                addr => match Number::try_from((addr, &self.heap)) {
+                    Ok(Number::Fixnum(n)) => hasher.write_str(1, &n.to_string()),
+                    Ok(Number::Integer(n)) => hasher.write_str(1, &n.to_string()),
+                    Ok(Number::Rational(r)) => hasher.write_str(2, &r.to_string()),
+                    Ok(Number::Float(OrderedFloat(f))) => {
+                        hasher.write_u64(3);
+                        hasher.write_u64(f.to_bits());
+                    }
+                    Err(_) => {}
+                },
+            }
+        }
+
+        hasher.hash
+    }
+
+    // unifies the second argument with the atom in the first argument
+    // after fold is applied to its text.
+    fn fold_atom_case(&mut self, fold: fn(&str) -> String) {
+        let string = match self.store(self.deref(self[temp_v!(1)])) {
+            Addr::Con(h) if self.heap.atom_at(h) => {
+                if let HeapCellValue::Atom(ref name, _) = &self.heap[h] {
+                    fold(name.as_str())
+                } else {
+                    unreachable!()
+                }
+            }
+            Addr::Char(c) => fold(&c.to_string()),
+            Addr::EmptyList => fold("[]"),
+            _ => unreachable!(),
+        };
+
+        let atom = if string == "[]" {
+            Addr::EmptyList
+        } else {
+            let name = clause_name!(string, self.atom_tbl);
+            self.heap.to_unifiable(HeapCellValue::Atom(name, None))
+        };
+
+        let a2 = self[temp_v!(2)];
+        (self.unify_fn)(self, a2, atom);
+    }
+
+    fn number_to_string(&self, n: Addr) -> String {
+        let string = match Number::try_from((n, &self.heap)) {
+            Ok(Number::Float(OrderedFloat(n))) => {
+                format!("{0:<20?}", n)
+            }
+            Ok(Number::Fixnum(n)) => n.to_string(),
+            Ok(Number::Integer(n)) => n.to_string(),
+            Ok(Number::Rational(r)) => {
+                // internally, Rational is assumed reduced.
+                if *r.denom() == 1 {
+                    r.numer().to_string()
+                } else {
+                    format!("{} rdiv {}", r.numer(), r.denom())
+                }
+            }
+            _ => {
+                unreachable!()
+            }
+        };
+
+        string.trim().to_string()
+    }
+
     fn call_continuation_chunk(&mut self, chunk: Addr, return_p: LocalCodePtr) -> LocalCodePtr {
         let chunk = self.store(self.deref(chunk));
 
@@ -1605,85 +2243,211 @@ impl MachineState {
                 }
             }
             &SystemClauseType::NumberToChars => {
-                let n = self[temp_v!(1)];
+                let n = self.store(self.deref(self[temp_v!(1)]));
                 let chs = self[temp_v!(2)];
 
-                let n = self.store(self.deref(n));
+                let string = self.number_to_string(n);
 
-                let string = match Number::try_from((n, &self.heap)) {
-                    Ok(Number::Float(OrderedFloat(n))) => {
-                        format!("{0:<20?}", n)
+                let chars = string.chars().map(|c| Addr::Char(c));
+                let char_list = Addr::HeapCell(self.heap.to_list(chars));
+
+                (self.unify_fn)(self, char_list, chs);
+            }
+            &SystemClauseType::NumberToCodes => {
+                let n = self.store(self.deref(self[temp_v!(1)]));
+                let chs = self[temp_v!(2)];
+
+                let string = self.number_to_string(n);
+
+                let codes = string.chars().map(|c| Addr::Fixnum(c as isize));
+
+                let codes_list = Addr::HeapCell(self.heap.to_list(codes));
+
+                (self.unify_fn)(self, codes_list, chs);
+            }
+            &SystemClauseType::CodesToNumber => {
+                let stub = MachineError::functor_stub(clause_name!("number_codes"), 2);
+
+                match self.try_from_list(temp_v!(1), stub) {
+                    Err(e) => {
+                        return Err(e);
                     }
-                    Ok(Number::Fixnum(n)) => n.to_string(),
-                    Ok(Number::Integer(n)) => n.to_string(),
-                    Ok(Number::Rational(r)) => {
-                        // n has already been confirmed as an integer, and
-                        // internally, Rational is assumed reduced, so its denominator
-                        // must be 1.
-                        r.numer().to_string()
+                    Ok(addrs) => match self.try_code_list(addrs) {
+                        Ok(chars) => {
+                            let stub = MachineError::functor_stub(clause_name!("number_codes"), 2);
+                            self.parse_number_from_string(chars, indices, stub)?;
+                        }
+                        Err(err) => {
+                            let stub = MachineError::functor_stub(clause_name!("number_codes"), 2);
+
+                            return Err(self.error_form(err, stub));
+                        }
+                    },
+                }
+            }
+            &SystemClauseType::InitLiftedHeapGroundCache => {
+                match self.store(self.deref(self[temp_v!(1)])) {
+                    Addr::Usize(lh_offset) => {
+                        let cache = LiftedHeapGroundCache::new(lh_offset, self.heap.h(), self.tr);
+
+                        self.lh_ground_caches.push(cache);
                     }
+                    _ => self.fail = true,
+                }
+            }
+            &SystemClauseType::LiftedHeapLength => {
+                let a1 = self[temp_v!(1)];
+                let lh_len = Addr::Usize(self.lifted_heap.h());
+
+                (self.unify_fn)(self, a1, lh_len);
+            }
+            &SystemClauseType::NumList => {
+                let lo = self.integer_arg(self[temp_v!(1)], "numlist", 3)?;
+                let hi = self.integer_arg(self[temp_v!(2)], "numlist", 3)?;
+
+                let (mut n, hi) = match (lo, hi) {
+                    (Some(lo), Some(hi)) => (lo, hi),
+                    _ => {
+                        let stub = MachineError::functor_stub(clause_name!("numlist"), 3);
+                        return Err(self.error_form(MachineError::instantiation_error(), stub));
+                    }
+                };
+
+                let values = std::iter::from_fn(move || {
+                    if n > hi {
+                        return None;
+                    }
+
+                    let value = match n.to_isize() {
+                        Some(i) => HeapCellValue::Addr(Addr::Fixnum(i)),
+                        None => HeapCellValue::Integer(Rc::new(n.clone())),
+                    };
+
+                    n += 1;
+                    Some(value)
+                });
+
+                let heap_addr = Addr::HeapCell(self.heap.to_list(values));
+
+                let r3 = self[temp_v!(3)];
+                (self.unify_fn)(self, r3, heap_addr);
+            }
+            &SystemClauseType::NumListStep => {
+                let lo = self.integer_arg(self[temp_v!(1)], "numlist_step", 4)?;
+                let step = self.integer_arg(self[temp_v!(2)], "numlist_step", 4)?;
+                let hi = self.integer_arg(self[temp_v!(3)], "numlist_step", 4)?;
+
+                let (mut n, step, hi) = match (lo, step, hi) {
+                    (Some(lo), Some(step), Some(hi)) => (lo, step, hi),
                     _ => {
-                        unreachable!()
+                        let stub = MachineError::functor_stub(clause_name!("numlist_step"), 4);
+                        return Err(self.error_form(MachineError::instantiation_error(), stub));
                     }
                 };
 
-                let chars = string.trim().chars().map(|c| Addr::Char(c));
-                let char_list = Addr::HeapCell(self.heap.to_list(chars));
+                let direction = step.cmp0();
 
-                (self.unify_fn)(self, char_list, chs);
-            }
-            &SystemClauseType::NumberToCodes => {
-                let n = self[temp_v!(1)];
-                let chs = self[temp_v!(2)];
+                if direction == Ordering::Equal {
+                    let stub = MachineError::functor_stub(clause_name!("numlist_step"), 4);
+                    let err = MachineError::domain_error(DomainErrorType::NonZero, Addr::Fixnum(0));
+
+                    return Err(self.error_form(err, stub));
+                }
 
-                let string = match Number::try_from((n, &self.heap)) {
-                    Ok(Number::Float(OrderedFloat(n))) => {
-                        format!("{0:<20?}", n)
+                let values = std::iter::from_fn(move || {
+                    if n.cmp(&hi) == direction {
+                        return None;
                     }
-                    Ok(Number::Fixnum(n)) => n.to_string(),
-                    Ok(Number::Integer(n)) => n.to_string(),
-                    Ok(Number::Rational(r)) => {
-                        // n has already been confirmed as an integer, and
-                        // internally, Rational is assumed reduced, so its
-                        // denominator must be 1.
-                        r.numer().to_string()
+
+                    let value = match n.to_isize() {
+                        Some(i) => HeapCellValue::Addr(Addr::Fixnum(i)),
+                        None => HeapCellValue::Integer(Rc::new(n.clone())),
+                    };
+
+                    n += &step;
+                    Some(value)
+                });
+
+                let heap_addr = Addr::HeapCell(self.heap.to_list(values));
+
+                let r4 = self[temp_v!(4)];
+                (self.unify_fn)(self, r4, heap_addr);
+            }
+            &SystemClauseType::StringCode => {
+                let index = match self.integer_arg(self[temp_v!(1)], "string_code", 3)? {
+                    Some(index) => index,
+                    None => {
+                        let stub = MachineError::functor_stub(clause_name!("string_code"), 3);
+                        return Err(self.error_form(MachineError::instantiation_error(), stub));
                     }
-                    _ => {
-                        unreachable!()
+                };
+
+                let c = match index.to_usize() {
+                    Some(index) if index > 0 => {
+                        self.heap_pstr_iter(self[temp_v!(2)]).nth_char(index - 1)
                     }
+                    _ => None,
                 };
 
-                let codes = string.trim().chars().map(|c| Addr::Fixnum(c as isize));
+                match c {
+                    Some(c) => {
+                        let code = Addr::Fixnum(c as isize);
+                        (self.unify_fn)(self, self[temp_v!(3)], code);
+                    }
+                    None => {
+                        self.fail = true;
+                    }
+                }
+            }
+            &SystemClauseType::StringChars => {
+                let a1 = self.store(self.deref(self[temp_v!(1)]));
+
+                if a1.is_ref() {
+                    let string = self.string_from_chars(self[temp_v!(2)], "string_chars")?;
+                    let pstr = self.heap.put_complete_string(&string);
 
-                let codes_list = Addr::HeapCell(self.heap.to_list(codes));
+                    (self.unify_fn)(self, a1, pstr);
+                } else {
+                    let string = self.string_from_chars(a1, "string_chars")?;
+                    let chars = Addr::HeapCell(self.heap.to_list(string.chars().map(Addr::Char)));
 
-                (self.unify_fn)(self, codes_list, chs);
+                    (self.unify_fn)(self, self[temp_v!(2)], chars);
+                }
             }
-            &SystemClauseType::CodesToNumber => {
-                let stub = MachineError::functor_stub(clause_name!("number_codes"), 2);
+            &SystemClauseType::StringCodes => {
+                let a1 = self.store(self.deref(self[temp_v!(1)]));
 
-                match self.try_from_list(temp_v!(1), stub) {
-                    Err(e) => {
-                        return Err(e);
-                    }
-                    Ok(addrs) => match self.try_char_list(addrs) {
-                        Ok(chars) => {
-                            let stub = MachineError::functor_stub(clause_name!("number_codes"), 2);
-                            self.parse_number_from_string(chars, indices, stub)?;
-                        }
-                        Err(err) => {
-                            let stub = MachineError::functor_stub(clause_name!("number_codes"), 2);
+                if a1.is_ref() {
+                    let string = self.string_from_codes(temp_v!(2), "string_codes")?;
+                    let pstr = self.heap.put_complete_string(&string);
 
-                            return Err(self.error_form(err, stub));
-                        }
-                    },
+                    (self.unify_fn)(self, a1, pstr);
+                } else {
+                    let string = self.string_from_chars(a1, "string_codes")?;
+                    let iter = string.chars().map(|c| Addr::Fixnum(c as isize));
+                    let codes = Addr::HeapCell(self.heap.to_list(iter));
+
+                    (self.unify_fn)(self, self[temp_v!(2)], codes);
                 }
             }
-            &SystemClauseType::LiftedHeapLength => {
-                let a1 = self[temp_v!(1)];
-                let lh_len = Addr::Usize(self.lifted_heap.h());
+            &SystemClauseType::TermHash => {
+                let hash = self.term_hash(self[temp_v!(1)]);
+                let hash = Addr::Fixnum((hash >> 1) as isize);
 
-                (self.unify_fn)(self, a1, lh_len);
+                (self.unify_fn)(self, self[temp_v!(2)], hash);
+            }
+            &SystemClauseType::MSort => {
+                self.check_sort_errors("msort")?;
+
+                let stub = MachineError::functor_stub(clause_name!("msort"), 2);
+                let mut list = self.try_from_list(temp_v!(1), stub)?;
+
+                list.sort_by(|a1, a2| self.compare_term_test(a1, a2).unwrap_or(Ordering::Less));
+
+                let heap_addr = Addr::HeapCell(self.heap.to_list(list.into_iter()));
+
+                let r2 = self[temp_v!(2)];
+                (self.unify_fn)(self, r2, heap_addr);
             }
             &SystemClauseType::CharCode => {
                 let a1 = self[temp_v!(1)];
@@ -1974,6 +2738,55 @@ impl MachineState {
                     }
                 }
             }
+            &SystemClauseType::DowncaseAtom => {
+                self.fold_atom_case(str::to_lowercase);
+            }
+            &SystemClauseType::UpcaseAtom => {
+                self.fold_atom_case(str::to_uppercase);
+            }
+            &SystemClauseType::Tab => {
+                let mut stream = self.get_stream_or_alias(self[temp_v!(1)], indices, "tab", 2)?;
+
+                self.check_stream_properties(
+                    &mut stream,
+                    StreamType::Text,
+                    None,
+                    clause_name!("tab"),
+                    2,
+                )?;
+
+                let n = match self.integer_arg(self[temp_v!(2)], "tab", 2)? {
+                    Some(n) => n,
+                    None => {
+                        let stub = MachineError::functor_stub(clause_name!("tab"), 2);
+                        return Err(self.error_form(MachineError::instantiation_error(), stub));
+                    }
+                };
+
+                if n < 0 {
+                    let stub = MachineError::functor_stub(clause_name!("tab"), 2);
+                    let err = MachineError::domain_error(
+                        DomainErrorType::NotLessThanZero,
+                        self.store(self.deref(self[temp_v!(2)])),
+                    );
+
+                    return Err(self.error_form(err, stub));
+                }
+
+                let n = match n.to_usize() {
+                    Some(n) => n,
+                    None => {
+                        let stub = MachineError::functor_stub(clause_name!("tab"), 2);
+                        let err = MachineError::representation_error(RepFlag::MaxInteger);
+
+                        return Err(self.error_form(err, stub));
+                    }
+                };
+
+                for _ in 0..n {
+                    write!(&mut stream, " ").unwrap();
+                }
+            }
             &SystemClauseType::PutChars => {
                 let mut stream =
                     self.get_stream_or_alias(self[temp_v!(1)], indices, "$put_chars", 2)?;
@@ -2324,7 +3137,9 @@ impl MachineState {
                     for c in buf {
                         string.push(c as char);
                     }
-                } else {
+                } else if num > 0 {
+                    // opening the parsing stream peeks a character, so
+                    // don't open it unless something is to be read.
                     let mut iter = self.open_parsing_stream(stream.clone(), "get_n_chars", 2)?;
 
                     for _ in 0..num {
@@ -2623,6 +3438,23 @@ impl MachineState {
                     }
                 }
             }
+            &SystemClauseType::CloseOutputString => {
+                let mut stream =
+                    self.get_stream_or_alias(self[temp_v!(1)], indices, "close_output_string", 2)?;
+
+                let string = match Stream::bytes(&stream) {
+                    Some(bytes) => String::from_utf8_lossy(&bytes).into_owned(),
+                    None => String::new(),
+                };
+
+                indices.streams.remove(&stream);
+                stream.close();
+
+                let chars = self.heap.put_complete_string(&string);
+                let chars_var = self[temp_v!(2)];
+
+                (self.unify_fn)(self, chars, chars_var);
+            }
             &SystemClauseType::CopyToLiftedHeap => match self.store(self.deref(self[temp_v!(1)])) {
                 Addr::Usize(lh_offset) => {
                     let copy_target = self[temp_v!(2)];
@@ -2800,8 +3632,8 @@ impl MachineState {
                     | addr @ Addr::AttrVar(_) => {
                         let mut iter = indices.code_dir.iter();
 
-                        while let Some(((name, arity), _)) = iter.next() {
-                            if is_builtin_predicate(&name) {
+                        while let Some(((name, arity), idx)) = iter.next() {
+                            if idx.is_undefined() || is_builtin_predicate(&name) {
                                 continue;
                             }
 
@@ -3001,6 +3833,60 @@ impl MachineState {
 
                 (self.unify_fn)(self, a1, addr);
             }
+            &SystemClauseType::AtomTableStatistics => {
+                let (hits, misses) = self.atom_tbl.stats();
+
+                let stats = [
+                    (self.atom_tbl.num_entries(), temp_v!(1)),
+                    (hits, temp_v!(2)),
+                    (misses, temp_v!(3)),
+                ];
+
+                for (n, r) in stats.iter() {
+                    let n = self.put_integer(Integer::from(*n));
+                    (self.unify_fn)(self, self[*r], n);
+
+                    if self.fail {
+                        return Ok(());
+                    }
+                }
+            }
+            &SystemClauseType::MemoryStatistics => {
+                let key = match self.store(self.deref(self[temp_v!(1)])) {
+                    Addr::Con(h) if self.heap.atom_at(h) => {
+                        if let HeapCellValue::Atom(ref name, _) = &self.heap[h] {
+                            name.clone()
+                        } else {
+                            unreachable!()
+                        }
+                    }
+                    _ => unreachable!(),
+                };
+
+                let (used, free) = match key.as_str() {
+                    "global_stack" => self.global_stack_usage(),
+                    "local_stack" => self.local_stack_usage(),
+                    "trail" => self.trail_usage(),
+                    _ => unreachable!(),
+                };
+
+                let stats = [(used, temp_v!(2)), (free, temp_v!(3))];
+
+                for (n, r) in stats.iter() {
+                    let n = self.put_integer(Integer::from(*n));
+                    (self.unify_fn)(self, self[*r], n);
+
+                    if self.fail {
+                        return Ok(());
+                    }
+                }
+            }
+            &SystemClauseType::InferenceCount => {
+                let a1 = self[temp_v!(1)];
+                let count = self.put_integer(Integer::from(self.inference_count));
+
+                (self.unify_fn)(self, a1, count);
+            }
             &SystemClauseType::CurrentTime => {
                 let str = self.systemtime_to_timestamp(SystemTime::now());
                 (self.unify_fn)(self, self[temp_v!(1)], str);
@@ -3117,6 +4003,27 @@ impl MachineState {
 
                 self.bind(stream_var.as_var().unwrap(), stream);
             }
+            &SystemClauseType::OpenInputString => {
+                let chars = self.heap_pstr_iter(self[temp_v!(1)]).to_string();
+                let stream = Stream::from(chars);
+
+                indices.streams.insert(stream.clone());
+
+                let stream = self.heap.to_unifiable(HeapCellValue::Stream(stream));
+                let stream_var = self.store(self.deref(self[temp_v!(2)]));
+
+                self.bind(stream_var.as_var().unwrap(), stream);
+            }
+            &SystemClauseType::OpenOutputString => {
+                let stream = Stream::from(String::new());
+
+                indices.streams.insert(stream.clone());
+
+                let stream = self.heap.to_unifiable(HeapCellValue::Stream(stream));
+                let stream_var = self.store(self.deref(self[temp_v!(1)]));
+
+                self.bind(stream_var.as_var().unwrap(), stream);
+            }
             &SystemClauseType::TruncateIfNoLiftedHeapGrowthDiff => {
                 self.truncate_if_no_lifted_heap_diff(|h| Addr::HeapCell(h))
             }
@@ -3227,6 +4134,8 @@ impl MachineState {
                 match self.store(self.deref(lh_offset)) {
                     Addr::Usize(lh_offset) => {
                         if lh_offset >= self.lifted_heap.h() {
+                            self.truncate_lifted_heap(lh_offset);
+
                             let solutions = self[temp_v!(2)];
                             let diff = self[temp_v!(3)];
 
@@ -3262,7 +4171,7 @@ impl MachineState {
                                 });
                             }
 
-                            self.lifted_heap.truncate(lh_offset);
+                            self.truncate_lifted_heap(lh_offset);
 
                             let solutions = self[temp_v!(2)];
                             (self.unify_fn)(self, Addr::HeapCell(h), solutions);
@@ -3279,6 +4188,8 @@ impl MachineState {
                 match self.store(self.deref(lh_offset)) {
                     Addr::Usize(lh_offset) => {
                         if lh_offset >= self.lifted_heap.h() {
+                            self.truncate_lifted_heap(lh_offset);
+
                             let solutions = self[temp_v!(2)];
                             (self.unify_fn)(self, solutions, Addr::EmptyList);
                         } else {
@@ -3295,7 +4206,7 @@ impl MachineState {
                                 }
                             }
 
-                            self.lifted_heap.truncate(lh_offset);
+                            self.truncate_lifted_heap(lh_offset);
 
                             let solutions = self[temp_v!(2)];
                             (self.unify_fn)(self, Addr::HeapCell(h), solutions);
@@ -3333,6 +4244,36 @@ impl MachineState {
                     }
                 }
             }
+            &SystemClauseType::GetMaxInteger => {
+                let a1 = self[temp_v!(1)];
+                (self.unify_fn)(self, a1, Addr::Fixnum(isize::MAX));
+            }
+            &SystemClauseType::GetMinInteger => {
+                let a1 = self[temp_v!(1)];
+                (self.unify_fn)(self, a1, Addr::Fixnum(isize::MIN));
+            }
+            &SystemClauseType::GetMaxTermDepth => {
+                let a1 = self[temp_v!(1)];
+
+                let max_term_depth = Integer::from(self.flags.max_term_depth);
+                let max_term_depth = self
+                    .heap
+                    .to_unifiable(HeapCellValue::Integer(Rc::new(max_term_depth)));
+
+                (self.unify_fn)(self, a1, max_term_depth);
+            }
+            &SystemClauseType::GetUnknown => {
+                let a1 = self[temp_v!(1)];
+
+                let value = match self.flags.unknown {
+                    Unknown::Error => clause_name!("error"),
+                    Unknown::Fail => clause_name!("fail"),
+                    Unknown::Warn => clause_name!("warning"),
+                };
+
+                let atom = self.heap.to_unifiable(HeapCellValue::Atom(value, None));
+                (self.unify_fn)(self, a1, atom);
+            }
             &SystemClauseType::GetSCCCleaner => {
                 let dest = self[temp_v!(1)];
 
@@ -3464,6 +4405,29 @@ impl MachineState {
                     }
                 };
             }
+            &SystemClauseType::IsImportedPredicate => {
+                let module_name = atom_from!(self, self.store(self.deref(self[temp_v!(1)])));
+                let source_name = atom_from!(self, self.store(self.deref(self[temp_v!(4)])));
+
+                let (name, arity) = self.read_predicate_key(self[temp_v!(2)], self[temp_v!(3)]);
+
+                // module and source share the definition exactly when
+                // module imported it from source without redefining it.
+                let source_index = indices
+                    .get_predicate_code_index(name.clone(), arity, source_name, None)
+                    .map(|index| index.get());
+
+                let module_index = indices
+                    .get_predicate_code_index(name, arity, module_name, None)
+                    .map(|index| index.get());
+
+                self.fail = match source_index {
+                    Some(IndexPtr::Index(_)) | Some(IndexPtr::DynamicIndex(_)) => {
+                        module_index != source_index
+                    }
+                    _ => true,
+                };
+            }
             &SystemClauseType::NoSuchPredicate => {
                 let module_name = atom_from!(self, self.store(self.deref(self[temp_v!(1)])));
 
@@ -3716,6 +4680,44 @@ impl MachineState {
                     self.fail = true;
                 }
             },
+            &SystemClauseType::SetMaxTermDepth => {
+                let max_term_depth = self.store(self.deref(self[temp_v!(1)]));
+
+                let max_term_depth = match Number::try_from((max_term_depth, &self.heap)) {
+                    Ok(Number::Integer(n)) => n.to_usize(),
+                    Ok(Number::Fixnum(n)) => usize::try_from(n).ok(),
+                    _ => None,
+                };
+
+                match max_term_depth {
+                    Some(max_term_depth) if max_term_depth > 0 => {
+                        self.flags.max_term_depth = max_term_depth;
+                    }
+                    _ => {
+                        self.fail = true;
+                    }
+                }
+            }
+            &SystemClauseType::SetUnknown => match self[temp_v!(1)] {
+                Addr::Con(h) if self.heap.atom_at(h) => {
+                    if let HeapCellValue::Atom(ref atom, _) = &self.heap[h] {
+                        self.flags.unknown = match atom.as_str() {
+                            "error" => Unknown::Error,
+                            "fail" => Unknown::Fail,
+                            "warning" => Unknown::Warn,
+                            _ => {
+                                self.fail = true;
+                                return Ok(());
+                            }
+                        };
+                    } else {
+                        unreachable!()
+                    }
+                }
+                _ => {
+                    self.fail = true;
+                }
+            },
             &SystemClauseType::InferenceLevel => {
                 let a1 = self[temp_v!(1)];
                 let a2 = self.store(self.deref(self[temp_v!(2)]));
@@ -3911,7 +4913,7 @@ impl MachineState {
                 current_input_stream.reset();
 
                 readline::set_prompt(true);
-                let result = self.read_term(current_input_stream.clone(), indices);
+                let result = self.read_term(current_input_stream.clone(), indices, None);
                 readline::set_prompt(false);
 
                 match result {
@@ -3922,24 +4924,74 @@ impl MachineState {
                     }
                 }
             }
+            &SystemClauseType::ReadNumber => {
+                let mut stream =
+                    self.get_stream_or_alias(self[temp_v!(1)], indices, "read_number", 2)?;
+
+                self.check_stream_properties(
+                    &mut stream,
+                    StreamType::Text,
+                    Some(self[temp_v!(2)]),
+                    clause_name!("read_number"),
+                    2,
+                )?;
+
+                let mut iter = self.open_parsing_stream(stream.clone(), "read_number", 2)?;
+
+                let mut lexer = NumberLexer::new(&mut iter);
+                let is_number = lexer.scan();
+                let NumberLexer {
+                    put_back, token, ..
+                } = lexer;
+
+                let mut buf = iter.take_buf();
+                buf.extend(put_back.into_iter().map(Ok));
+
+                let stub = MachineError::functor_stub(clause_name!("read_number"), 2);
+
+                if let Err(e) = stream.pause_stream(buf) {
+                    let err = MachineError::session_error(self.heap.h(), SessionError::from(e));
+                    return Err(self.error_form(err, stub));
+                }
+
+                if is_number {
+                    self.parse_number_from_string(token, indices, stub)?;
+                } else {
+                    self.fail = true;
+                }
+            }
             &SystemClauseType::ReadTerm => {
                 readline::set_prompt(false);
 
                 let stream = self.get_stream_or_alias(self[temp_v!(1)], indices, "read_term", 3)?;
 
-                self.read_term(stream, indices)?;
+                // the layout is requested by passing [Layout] rather than [].
+                let layout = match self.store(self.deref(self[temp_v!(6)])) {
+                    Addr::Lis(l) => Some(Addr::HeapCell(l)),
+                    _ => None,
+                };
+
+                self.read_term(stream, indices, layout)?;
             }
             &SystemClauseType::ReadTermFromChars => {
                 let mut heap_pstr_iter = self.heap_pstr_iter(self[temp_v!(1)]);
                 let chars = heap_pstr_iter.to_string();
 
+                // as with $read_term, the layout is requested by
+                // passing [Layout] rather than [].
+                let layout = match self.store(self.deref(self[temp_v!(6)])) {
+                    Addr::Lis(l) => Some(Addr::HeapCell(l)),
+                    _ => None,
+                };
+
                 if let Addr::EmptyList = heap_pstr_iter.focus() {
-                    let term_write_result = match self.read(
+                    let (term_write_result, term_layout) = match self.read_with_layout(
                         Stream::from(chars),
                         self.atom_tbl.clone(),
                         &indices.op_dir,
+                        layout.is_some(),
                     ) {
-                        Ok(term_write_result) => term_write_result,
+                        Ok(result) => result,
                         Err(e) => {
                             let stub =
                                 MachineError::functor_stub(clause_name!("read_term_from_chars"), 2);
@@ -3958,6 +5010,23 @@ impl MachineState {
                     } else {
                         unreachable!()
                     }
+
+                    if let (Some(layout), Some(term_layout)) = (layout, term_layout) {
+                        let term_layout = write_layout_to_heap(&term_layout, self);
+                        (self.unify_fn)(self, term_layout, layout);
+
+                        if self.fail {
+                            return Ok(());
+                        }
+                    }
+
+                    let read_term_vars = self.read_term_vars(
+                        &term_write_result,
+                        ReadTermOptions::all(),
+                        &indices.op_dir,
+                    );
+
+                    self.unify_read_term_vars(read_term_vars);
                 } else {
                     unreachable!()
                 }
@@ -4000,6 +5069,60 @@ impl MachineState {
                     return Err(err);
                 }
             }
+            &SystemClauseType::Succ => {
+                let a1 = self[temp_v!(1)];
+                let a2 = self[temp_v!(2)];
+
+                let n1 = self.not_less_than_zero_arg(a1, "succ", 2)?;
+                let n2 = self.not_less_than_zero_arg(a2, "succ", 2)?;
+
+                match (n1, n2) {
+                    (Some(n1), _) => {
+                        let n = self.put_integer(n1 + Integer::from(1));
+                        (self.unify_fn)(self, n, a2);
+                    }
+                    (None, Some(n2)) => {
+                        if n2 == 0 {
+                            self.fail = true;
+                        } else {
+                            let n = self.put_integer(n2 + Integer::from(-1));
+                            (self.unify_fn)(self, n, a1);
+                        }
+                    }
+                    (None, None) => {
+                        let stub = MachineError::functor_stub(clause_name!("succ"), 2);
+                        return Err(self.error_form(MachineError::instantiation_error(), stub));
+                    }
+                }
+            }
+            &SystemClauseType::Plus => {
+                let a1 = self[temp_v!(1)];
+                let a2 = self[temp_v!(2)];
+                let a3 = self[temp_v!(3)];
+
+                let n1 = self.integer_arg(a1, "plus", 3)?;
+                let n2 = self.integer_arg(a2, "plus", 3)?;
+                let n3 = self.integer_arg(a3, "plus", 3)?;
+
+                match (n1, n2, n3) {
+                    (Some(n1), Some(n2), _) => {
+                        let n = self.put_integer(n1 + n2);
+                        (self.unify_fn)(self, n, a3);
+                    }
+                    (Some(n1), None, Some(n3)) => {
+                        let n = self.put_integer(n3 + (-n1));
+                        (self.unify_fn)(self, n, a2);
+                    }
+                    (None, Some(n2), Some(n3)) => {
+                        let n = self.put_integer(n3 + (-n2));
+                        (self.unify_fn)(self, n, a1);
+                    }
+                    _ => {
+                        let stub = MachineError::functor_stub(clause_name!("plus"), 3);
+                        return Err(self.error_form(MachineError::instantiation_error(), stub));
+                    }
+                }
+            }
             &SystemClauseType::Sleep => {
                 let time = self.store(self.deref(self[temp_v!(1)]));
 
@@ -4533,7 +5656,7 @@ impl MachineState {
             }
             &SystemClauseType::TruncateLiftedHeapTo => {
                 match self.store(self.deref(self[temp_v!(1)])) {
-                    Addr::Usize(lh_offset) => self.lifted_heap.truncate(lh_offset),
+                    Addr::Usize(lh_offset) => self.truncate_lifted_heap(lh_offset),
                     _ => self.fail = true,
                 }
             }
@@ -0,0 +1,157 @@
+use prolog_parser::ast::*;
+use prolog_parser::clause_name;
+use prolog_parser::tabled_rc::*;
+
+use crate::machine::heap::*;
+use crate::machine::machine_indices::*;
+
+// terms described as Values are written to and read back from the
+// heap so that heap tests can be table-driven rather than built from
+// hand-written cells.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum Value {
+    Atom(String),
+    Integer(isize),
+    List(Vec<Value>),
+    Structure(String, Vec<Value>),
+}
+
+fn put_value(heap: &mut Heap, value: &Value, atom_tbl: &mut TabledData<Atom>) -> Addr {
+    match value {
+        Value::Atom(name) => {
+            heap.put_constant(Constant::Atom(clause_name!(name.clone(), atom_tbl), None))
+        }
+        Value::Integer(n) => heap.put_constant(Constant::Fixnum(*n)),
+        Value::List(values) if values.is_empty() => Addr::EmptyList,
+        Value::List(values) => {
+            let addrs: Vec<_> = values
+                .iter()
+                .map(|value| HeapCellValue::Addr(put_value(heap, value, atom_tbl)))
+                .collect();
+
+            Addr::Lis(heap.to_list(addrs.into_iter()) + 1)
+        }
+        Value::Structure(name, args) => {
+            let addrs: Vec<_> = args
+                .iter()
+                .map(|value| HeapCellValue::Addr(put_value(heap, value, atom_tbl)))
+                .collect();
+
+            let name = clause_name!(name.clone(), atom_tbl);
+            let h = heap.push(HeapCellValue::NamedStr(addrs.len(), name, None));
+
+            heap.extend(addrs.into_iter());
+
+            Addr::Str(h)
+        }
+    }
+}
+
+// writes value to the heap and returns the location of the cell
+// holding it.
+pub(crate) fn build_value_on_heap(
+    heap: &mut Heap,
+    value: &Value,
+    atom_tbl: &mut TabledData<Atom>,
+) -> usize {
+    let addr = put_value(heap, value, atom_tbl);
+    heap.push(HeapCellValue::Addr(addr))
+}
+
+fn deref(heap: &Heap, mut addr: Addr) -> Addr {
+    loop {
+        match addr {
+            Addr::HeapCell(h) | Addr::AttrVar(h) => match heap[h].as_addr(h) {
+                value if value != addr => addr = value,
+                _ => return addr,
+            },
+            _ => return addr,
+        }
+    }
+}
+
+fn read_addr(heap: &Heap, addr: Addr) -> Option<Value> {
+    match deref(heap, addr) {
+        Addr::Con(h) => match &heap[h] {
+            HeapCellValue::Atom(name, _) => Some(Value::Atom(name.as_str().to_string())),
+            _ => None,
+        },
+        Addr::Fixnum(n) => Some(Value::Integer(n)),
+        Addr::EmptyList => Some(Value::List(vec![])),
+        Addr::Lis(mut l) => {
+            let mut values = vec![];
+
+            loop {
+                values.push(read_addr(heap, Addr::HeapCell(l))?);
+
+                match deref(heap, Addr::HeapCell(l + 1)) {
+                    Addr::Lis(tail) => l = tail,
+                    Addr::EmptyList => return Some(Value::List(values)),
+                    _ => return None,
+                }
+            }
+        }
+        Addr::Str(s) => match &heap[s] {
+            HeapCellValue::NamedStr(arity, name, _) => {
+                let args = (s + 1..s + 1 + arity)
+                    .map(|h| read_addr(heap, Addr::HeapCell(h)))
+                    .collect::<Option<Vec<_>>>()?;
+
+                Some(Value::Structure(name.as_str().to_string(), args))
+            }
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+// reads back the value held by the heap cell h, or None if it
+// contains anything a Value can't describe, such as a variable.
+pub(crate) fn read_value_from_heap(heap: &Heap, h: usize) -> Option<Value> {
+    read_addr(heap, Addr::HeapCell(h))
+}
+
+mod tests {
+    use super::*;
+
+    use std::rc::Rc;
+
+    fn atom(name: &str) -> Value {
+        Value::Atom(name.to_string())
+    }
+
+    fn structure(name: &str, args: Vec<Value>) -> Value {
+        Value::Structure(name.to_string(), args)
+    }
+
+    #[test]
+    fn heap_round_trip() {
+        let mut atom_tbl = TabledData::new(Rc::new("user".to_string()));
+        let mut heap = Heap::new();
+
+        let values = vec![
+            atom("a"),
+            Value::Integer(-42),
+            Value::List(vec![]),
+            Value::List(vec![atom("a"), Value::Integer(1), atom("b")]),
+            Value::List(vec![
+                Value::List(vec![Value::Integer(1), Value::Integer(2)]),
+                Value::List(vec![]),
+                Value::List(vec![Value::List(vec![atom("c")])]),
+            ]),
+            structure("f", vec![atom("x"), Value::Integer(3)]),
+            structure(
+                "g",
+                vec![
+                    structure("h", vec![Value::List(vec![atom("y"), atom("z")])]),
+                    Value::List(vec![structure("-", vec![atom("k"), Value::Integer(0)])]),
+                ],
+            ),
+        ];
+
+        for value in values {
+            let h = build_value_on_heap(&mut heap, &value, &mut atom_tbl);
+            assert_eq!(read_value_from_heap(&heap, h), Some(value));
+        }
+    }
+}
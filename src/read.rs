@@ -7,8 +7,10 @@ use crate::iterators::*;
 use crate::machine::machine_indices::*;
 use crate::machine::machine_state::MachineState;
 use crate::machine::streams::Stream;
+use crate::rug::Integer;
 
 use std::collections::VecDeque;
+use std::rc::Rc;
 
 type SubtermDeque = VecDeque<(usize, usize)>;
 
@@ -196,20 +198,39 @@ impl MachineState {
 
     pub(crate) fn read(
         &mut self,
-        mut inner: Stream,
+        inner: Stream,
         atom_tbl: TabledData<Atom>,
         op_dir: &OpDir,
     ) -> Result<TermWriteResult, ParserError> {
+        self.read_with_layout(inner, atom_tbl, op_dir, false)
+            .map(|(term_write_result, _)| term_write_result)
+    }
+
+    // the layout of the term read is only built if with_layout is set.
+    pub(crate) fn read_with_layout(
+        &mut self,
+        mut inner: Stream,
+        atom_tbl: TabledData<Atom>,
+        op_dir: &OpDir,
+        with_layout: bool,
+    ) -> Result<(TermWriteResult, Option<TermLayout>), ParserError> {
         let mut stream = parsing_stream(inner.clone())?;
 
-        let (term, num_lines_read) = {
+        let (term, layout, num_lines_read) = {
             let prior_num_lines_read = inner.lines_read();
             let mut parser = Parser::new(&mut stream, atom_tbl, self.flags);
+            let op_dir = CompositeOpDir::new(op_dir, None);
 
             parser.add_lines_read(prior_num_lines_read);
 
-            let term = parser.read_term(&CompositeOpDir::new(op_dir, None))?;
-            (term, parser.num_lines_read() - prior_num_lines_read)
+            let (term, layout) = if with_layout {
+                let (term, layout) = parser.read_term_with_layout(&op_dir)?;
+                (term, Some(layout))
+            } else {
+                (parser.read_term(&op_dir)?, None)
+            };
+
+            (term, layout, parser.num_lines_read() - prior_num_lines_read)
         };
 
         inner.add_lines_read(num_lines_read);
@@ -221,10 +242,44 @@ impl MachineState {
         let buf = stream.take_buf();
         inner.pause_stream(buf)?;
 
-        Ok(write_term_to_heap(&term, self))
+        Ok((write_term_to_heap(&term, self), layout))
     }
 }
 
+/// Writes `layout` to the heap as a term `span(StartLine, StartCol,
+/// EndLine, EndCol, Args)`, where `Args` is the list of the spans of
+/// the arguments of the laid out term.
+pub(crate) fn write_layout_to_heap(layout: &TermLayout, machine_st: &mut MachineState) -> Addr {
+    let args: Vec<_> = layout
+        .args
+        .iter()
+        .map(|arg| write_layout_to_heap(arg, machine_st))
+        .collect();
+
+    let args = Addr::HeapCell(machine_st.heap.to_list(args.into_iter()));
+
+    let Span {
+        start: (start_line, start_col),
+        end: (end_line, end_col),
+    } = layout.span;
+
+    let h = machine_st.heap.h();
+
+    let span = functor!(
+        "span",
+        [
+            integer(start_line),
+            integer(start_col),
+            integer(end_line),
+            integer(end_col),
+            addr(args)
+        ]
+    );
+
+    machine_st.heap.extend(span.into_iter());
+    Addr::HeapCell(h)
+}
+
 #[inline]
 pub(crate) fn write_term_to_heap(term: &Term, machine_st: &mut MachineState) -> TermWriteResult {
     let term_writer = TermWriter::new(machine_st);
@@ -244,6 +299,36 @@ pub(crate) struct TermWriteResult {
     pub(crate) var_dict: HeapVarDict,
 }
 
+/// Selects the variable lists `MachineState::read_term_vars` builds
+/// for a term just read, after the options of `read_term/3`.
+#[derive(Debug, Clone, Copy, Default)]
+pub(crate) struct ReadTermOptions {
+    pub(crate) singletons: bool,
+    pub(crate) variables: bool,
+    pub(crate) variable_names: bool,
+}
+
+impl ReadTermOptions {
+    #[inline]
+    pub(crate) fn all() -> Self {
+        ReadTermOptions {
+            singletons: true,
+            variables: true,
+            variable_names: true,
+        }
+    }
+}
+
+/// The heap lists requested by a `ReadTermOptions`. `singletons` and
+/// `variable_names` are lists of `Name = Var` pairs, `variables` is
+/// the list of variables of the term in depth-first order.
+#[derive(Debug, Default)]
+pub(crate) struct ReadTermVars {
+    pub(crate) singletons: Option<Addr>,
+    pub(crate) variables: Option<Addr>,
+    pub(crate) variable_names: Option<Addr>,
+}
+
 impl<'a> TermWriter<'a> {
     #[inline]
     fn new(machine_st: &'a mut MachineState) -> Self {
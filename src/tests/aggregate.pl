@@ -0,0 +1,29 @@
+:- module(tests_on_aggregate, []).
+
+:- use_module(library(aggregate)).
+:- use_module(library(between)).
+:- use_module(library(lists)).
+
+test_queries_on_aggregate_all :-
+    aggregate_all(count, member(_, [a,b,c]), N0),
+    N0 == 3,
+    aggregate_all(count, fail, N1),
+    N1 == 0,
+    aggregate_all(sum(X*2), member(X, [1,2,3]), S0),
+    S0 == 12,
+    aggregate_all(sum(X), member(X, []), S1),
+    S1 == 0,
+    aggregate_all(sum(X), member(X, [1, 0.5]), S2),
+    S2 =:= 1.5,
+    aggregate_all(bag(X-Y), member(X-Y, [b-1, a-2, b-1]), B0),
+    B0 == [b-1, a-2, b-1],
+    aggregate_all(count, (between(1, 3, I), aggregate_all(count, between(1, I, _), 2)), N2),
+    N2 == 1,
+    aggregate_all(sum(C), (member(L, [[a], [b,c]]), aggregate_all(count, member(_, L), C)), S3),
+    S3 == 3,
+    \+ aggregate_all(count, member(_, [a]), 2),
+    catch(aggregate_all(_, true, _), error(instantiation_error, _), true),
+    catch(aggregate_all(foo, true, _), error(domain_error(aggregate_spec, foo), _), true),
+    catch(aggregate_all(sum(a), true, _), error(type_error(evaluable, a/0), _), true).
+
+:- initialization(test_queries_on_aggregate_all).
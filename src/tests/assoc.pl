@@ -0,0 +1,31 @@
+:- module(tests_on_assoc, []).
+
+:- use_module(library(assoc)).
+:- use_module(library(lists)).
+
+put_pair(K-V, A0, A) :-
+    put_assoc(K, A0, V, A).
+
+test_queries_on_assoc :-
+    empty_assoc(A0),
+    foldl(put_pair, [d-4, b-2, f(x)-6, a-1, "c"-3, 1.0-0], A0, A1),
+    assoc_to_list(A1, Ps0),
+    Ps0 == [1.0-0, a-1, b-2, d-4, f(x)-6, "c"-3],
+    assoc_to_keys(A1, Ks0),
+    Ks0 == [1.0, a, b, d, f(x), "c"],
+    get_assoc(b, A1, V0),
+    V0 == 2,
+    \+ get_assoc(c, A1, _),
+    put_assoc(b, A1, 20, A2),
+    get_assoc(b, A2, V1),
+    V1 == 20,
+    get_assoc(b, A1, V2),
+    V2 == 2,
+    list_to_assoc([z-26, y-25, x-24], A3),
+    assoc_to_list(A3, Ps1),
+    Ps1 == [x-24, y-25, z-26],
+    max_assoc(A3, K0, _),
+    K0 == z,
+    catch(list_to_assoc([a-1, a-2], _), error(domain_error(unique_key_pairs, _), _), true).
+
+:- initialization(test_queries_on_assoc).
@@ -0,0 +1,62 @@
+:- module(tests_on_between, []).
+
+:- use_module(library(between)).
+
+test_queries_on_between :-
+    findall(X, between(1, 3, X), L0),
+    L0 == [1,2,3],
+    findall(X, between(3, 3, X), L1),
+    L1 == [3],
+    findall(X, between(4, 3, X), L2),
+    L2 == [],
+    between(1, 3, 2),
+    \+ between(1, 3, 4),
+    \+ between(1, 3, 0),
+    between(1, inf, 1000000),
+    \+ between(1, infinite, 0),
+    findall(X, (between(5, inf, X), (X >= 7, ! ; true)), L3),
+    L3 == [5,6,7],
+    catch(between(a, 3, _), error(type_error(integer, a), _), true),
+    catch(between(1, foo, _), error(type_error(integer, foo), _), true),
+    catch(between(1, 3, b), error(type_error(integer, b), _), true),
+    catch(between(_, 3, _), error(instantiation_error, _), true).
+
+test_queries_on_numlist :-
+    numlist(1, 5, L0),
+    L0 == [1,2,3,4,5],
+    numlist(3, 3, L1),
+    L1 == [3],
+    numlist(5, 1, L2),
+    L2 == [],
+    numlist(-2, 1, L3),
+    L3 == [-2,-1,0,1],
+    current_prolog_flag(max_integer, Max),
+    Below is Max - 1,
+    Above is Max + 1,
+    numlist(Below, Above, L4),
+    L4 == [Below, Max, Above],
+    \+ numlist(1, 3, [1,2]),
+    catch(numlist(a, 3, _), error(type_error(integer, a), _), true),
+    catch(numlist(1, 3.0, _), error(type_error(integer, 3.0), _), true).
+
+test_queries_on_numlist_step :-
+    numlist_step(1, 2, 9, L0),
+    L0 == [1,3,5,7,9],
+    numlist_step(1, 3, 8, L1),
+    L1 == [1,4,7],
+    numlist_step(5, -2, 0, L2),
+    L2 == [5,3,1],
+    numlist_step(1, -1, 3, L3),
+    L3 == [],
+    current_prolog_flag(max_integer, Max),
+    Below is Max - 1,
+    Above is Max + 1,
+    numlist_step(Below, 1, Above, L4),
+    L4 == [Below, Max, Above],
+    catch(numlist_step(1, 0, 3, _), error(domain_error(nonzero, 0), _), true),
+    catch(numlist_step(1, a, 3, _), error(type_error(integer, a), _), true),
+    catch(numlist_step(1, _, 3, _), error(instantiation_error, _), true).
+
+:- initialization(test_queries_on_between).
+:- initialization(test_queries_on_numlist).
+:- initialization(test_queries_on_numlist_step).
@@ -1,5 +1,6 @@
 :- module(tests_on_builtins, []).
 
+:- use_module(library(arithmetic)).
 :- use_module(library(lists)).
 :- use_module(library(iso_ext)).
 
@@ -128,3 +129,59 @@ test_queries_on_builtins :-
     \+ \+ findall(X, keysort([X-1,1-1],[2-1,1-1]), [2]).
 
 :- initialization(test_queries_on_builtins).
+
+test_queries_on_char_code :-
+    char_code(a, C0),
+    C0 == 97,
+    char_code(X0, 97),
+    X0 == a,
+    char_code(a, 97),
+    \+ char_code(a, 98),
+    char_code(X1, 233),
+    X1 == 'é',
+    char_code('é', C1),
+    C1 == 233,
+    catch(char_code(_, _), error(instantiation_error, _), true),
+    catch(char_code(ab, _), error(type_error(character, ab), _), true),
+    catch(char_code(1, _), error(type_error(character, 1), _), true),
+    catch(char_code(f(x), _), error(type_error(character, f(x)), _), true),
+    catch(char_code(_, a), error(type_error(integer, a), _), true),
+    catch(char_code(a, x), error(type_error(integer, x), _), true),
+    catch(char_code(_, -1), error(representation_error(character_code), _), true).
+
+:- initialization(test_queries_on_char_code).
+
+test_queries_on_call_n :-
+    call(plus(1), 2, X),
+    X == 3,
+    call(plus, 1, 2, Y),
+    Y == 3,
+    apply(plus(1), [2, Z]),
+    Z == 3,
+    apply(=, [a, W]),
+    W == a,
+    call(=(f(A, B, C, D, E, F, G)), f(1, 2, 3, 4, 5, 6, 7)),
+    [A, B, C, D, E, F, G] == [1, 2, 3, 4, 5, 6, 7],
+    catch(apply(_, []), error(instantiation_error, _), true),
+    catch(apply(1, [a]), error(type_error(callable, 1), _), true).
+
+:- initialization(test_queries_on_call_n).
+
+test_queries_on_variant :-
+    f(X, Y) =@= f(_, _),
+    \+ f(X, X) =@= f(_, _),
+    f(X, X) \=@= f(_, _),
+    f(X, Y, X) =@= f(A, B, A),
+    \+ f(X, Y, X) =@= f(A, B, B),
+    \+ f(X, Y) =@= f(A, A),
+    var(X), var(Y), var(A), var(B),
+    f(a, [1, 2], "ab") =@= f(a, [1, 2], [a, b]),
+    \+ f(a) =@= f(b),
+    f(a) \=@= f(b),
+    \+ f(X) =@= f(a),
+    \+ f(X, a) =@= f(Y, b),
+    [X|Y] =@= [A|B],
+    T0 = g(T0), T1 = g(T1),
+    T0 =@= T1.
+
+:- initialization(test_queries_on_variant).
@@ -52,3 +52,54 @@ test_queries_on_call_with_inference_limit :-
 	     [!,5]]).
 
 :- initialization(test_queries_on_call_with_inference_limit).
+
+count_down(0) :- !.
+count_down(N) :-
+    N1 is N - 1,
+    count_down(N1).
+
+test_queries_on_call_with_depth_limit :-
+    call_with_depth_limit(count_down(3), 100, R0),
+    integer(R0),
+    R0 =< 100,
+    call_with_depth_limit(count_down(100), 5, R1),
+    R1 == depth_limit_exceeded,
+    catch(call_with_depth_limit(throw(error), 5, _), error, true),
+    \+ call_with_depth_limit(fail, 5, _).
+
+:- initialization(test_queries_on_call_with_depth_limit).
+
+test_queries_on_statistics :-
+    statistics(inferences, I0),
+    integer(I0),
+    count_down(50),
+    statistics(inferences, I1),
+    I1 > I0,
+    statistics(runtime, [T0, _]),
+    statistics(runtime, [T1, S1]),
+    integer(T1),
+    T1 >= T0,
+    S1 =:= T1 - T0,
+    catch(statistics(_, _), error(instantiation_error, _), true),
+    catch(statistics(no_such_key, _),
+          error(domain_error(statistics_key, no_such_key), _),
+          true).
+
+:- initialization(test_queries_on_statistics).
+
+test_queries_on_memory_statistics :-
+    statistics(global_stack, [Used0, Free0]),
+    integer(Used0),
+    integer(Free0),
+    length(L, 1000),
+    statistics(global_stack, [Used1, _]),
+    Used1 >= Used0 + 1000,
+    L = [_|_],
+    statistics(local_stack, [LocalUsed, LocalFree]),
+    LocalUsed > 0,
+    LocalFree >= 0,
+    statistics(trail, [TrailUsed, TrailFree]),
+    TrailUsed >= 0,
+    TrailFree >= 0.
+
+:- initialization(test_queries_on_memory_statistics).
@@ -0,0 +1,31 @@
+:- module(tests_on_char_io, []).
+
+:- use_module(library(charsio)).
+:- use_module(library(iso_ext)).
+
+test_queries_on_char_io :-
+    open_chars_stream("aé", S0),
+    peek_char(S0, C0),
+    C0 == a,
+    peek_char(S0, C1),
+    C1 == a,
+    get_char(S0, C2),
+    C2 == a,
+    peek_char(S0, C3),
+    C3 == 'é',
+    get_char(S0, C4),
+    C4 == 'é',
+    peek_char(S0, C5),
+    C5 == end_of_file,
+    close(S0),
+    open_chars_stream("b", S1),
+    \+ peek_char(S1, c),
+    get_char(S1, C6),
+    C6 == b,
+    get_char(S1, C7),
+    C7 == end_of_file,
+    close(S1),
+    with_output_to(chars(Cs), (put_char(x), put_char('ü'))),
+    Cs == "xü".
+
+:- initialization(test_queries_on_char_io).
@@ -0,0 +1,17 @@
+:- module(tests_on_clpz_domains, []).
+
+:- use_module(library(clpz)).
+
+test_queries_on_in :-
+    X in 1..3,
+    X = 2,
+    \+ ( Y in 1..3, Y = 5 ),
+    Z in 1..3,
+    Z in 2..5,
+    fd_dom(Z, Dom),
+    Dom == 2..3,
+    findall(W, (W in 1..3, label([W])), Ws),
+    Ws == [1, 2, 3],
+    \+ ( V in 1..3, V in 4..6 ).
+
+:- initialization(test_queries_on_in).
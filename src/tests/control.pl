@@ -0,0 +1,103 @@
+:- module(tests_on_control, []).
+
+:- use_module(library(debug), [assertion/1]).
+:- use_module(library(iso_ext)).
+:- use_module(library(lists)).
+
+test_queries_on_control :-
+    call_cleanup(once((member(X, [1,2,3]), X > 0)), Det0 = true),
+    X == 1,
+    Det0 == true,
+    G = (member(Y, [1,2,3]), Y > 1),
+    call_cleanup(once(G), Det1 = true),
+    Y == 2,
+    Det1 == true,
+    call_cleanup(call(once, member(Z, [a,b])), Det2 = true),
+    Z == a,
+    Det2 == true,
+    \+ once(fail),
+    findall(W, once(member(W, [x,y])), [x]),
+    findall(V, (member(V, [1,2]), once(true)), [1,2]),
+    catch(once(_), error(instantiation_error, _), true).
+
+:- initialization(test_queries_on_control).
+
+test_queries_on_catch :-
+    catch(( X = bound, throw(oops) ), oops, ( var(X), Seen = unbound )),
+    var(X),
+    Seen == unbound,
+    catch(( Y = f(Z), Z = 1, throw(ball(Y)) ), ball(B), true),
+    var(Y),
+    B == f(1),
+    catch(catch(( W = 1, throw(outer) ), inner, true), outer, var(W)),
+    var(W),
+    catch(catch(throw(first), first, throw(second)), second, true),
+    \+ catch(( V = 1, throw(fails) ), fails, nonvar(V)).
+
+:- initialization(test_queries_on_catch).
+
+thrower(0, Ball) :-
+    throw(Ball).
+thrower(N, Ball) :-
+    N > 0,
+    N1 is N - 1,
+    thrower(N1, Ball),
+    true.
+
+test_queries_on_throw :-
+    Ball0 = ball(f(X, "text", [1, 2.5]), X, Y, Y),
+    catch(thrower(10, Ball0), Caught0, true),
+    Caught0 = ball(f(A, Text, List), B, C, D),
+    A == B,
+    C == D,
+    var(A),
+    var(C),
+    A \== C,
+    Text == "text",
+    List == [1, 2.5],
+    Ball1 = g(Ball1),
+    catch(thrower(3, Ball1), Caught1, true),
+    Caught1 = g(g(g(_))).
+
+:- initialization(test_queries_on_throw).
+
+test_queries_on_ignore :-
+    ignore(fail),
+    findall(X, ignore(member(X, [a, b, c])), Xs),
+    Xs == [a],
+    call_cleanup(ignore(member(_, [1, 2])), Det = true),
+    Det == true,
+    catch(ignore(throw(ball)), Ball, true),
+    Ball == ball.
+
+:- initialization(test_queries_on_ignore).
+
+test_queries_on_forall :-
+    forall(member(X, [1, 2, 3]), X > 0),
+    \+ forall(member(Y, [1, -2, 3]), Y > 0),
+    forall(fail, false),
+    forall(member(Z, [a, b]), var(_)),
+    var(Z),
+    call_cleanup(forall(member(W, [1, 2]), integer(W)), Det = true),
+    Det == true,
+    catch(forall(member(V, [1, 2]), ( V < 2 -> true ; throw(test) )), Ball0, true),
+    Ball0 == test,
+    catch(forall(throw(generate), true), Ball1, true),
+    Ball1 == generate.
+
+:- initialization(test_queries_on_forall).
+
+test_queries_on_assertion :-
+    assertion(true),
+    assertion(member(X, [a, b])),
+    X == a,
+    catch(assertion(member(c, [a, b])), assertion_failed(G0), true),
+    G0 == member(c, [a, b]),
+    catch(assertion(fail), assertion_failed(G1), true),
+    G1 == fail,
+    catch(assertion(throw(oops)), E, true),
+    E == oops,
+    findall(Y, assertion(member(Y, [1, 2, 3])), Ys),
+    Ys == [1].
+
+:- initialization(test_queries_on_assertion).
@@ -0,0 +1,48 @@
+:- module(tests_on_copy_term, []).
+
+:- use_module(library(atts)).
+:- use_module(library(dif)).
+:- use_module(library(freeze)).
+:- use_module(library(lists)).
+
+test_queries_on_copy_term :-
+    copy_term(f(X, X, Y), C0),
+    C0 = f(A, B, D),
+    A == B,
+    A \== D,
+    A \== X,
+    D \== Y,
+    copy_term(g(a, "bc", 1.5), C1),
+    C1 == g(a, "bc", 1.5),
+    T = f(T, Z),
+    copy_term(T, C2),
+    \+ acyclic_term(C2),
+    C2 = f(C3, W),
+    C3 = f(_, W0),
+    W == W0,
+    W \== Z,
+    L = [a, b|L],
+    copy_term(L, L0),
+    \+ acyclic_term(L0),
+    L0 = [a, b, a, b|_].
+
+test_queries_on_copy_term_goals :-
+    dif(X, b),
+    copy_term(f(X), C0, Gs0),
+    C0 = f(Y),
+    Gs0 == [dif:dif(Y, b)],
+    \+ X = b,
+    \+ \+ Y = b,
+    maplist(call, Gs0),
+    \+ Y = b,
+    freeze(Z, true),
+    copy_term(Z, W, Gs1),
+    Gs1 = [freeze:freeze(W0, _)],
+    W == W0,
+    copy_term(g(_, a), C1, Gs2),
+    C1 = g(V, a),
+    var(V),
+    Gs2 == [].
+
+:- initialization(test_queries_on_copy_term).
+:- initialization(test_queries_on_copy_term_goals).
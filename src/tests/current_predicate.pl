@@ -0,0 +1,23 @@
+:- module(tests_on_current_predicate, []).
+
+:- use_module(library(lists)).
+
+user:cp_foo(1).
+user:cp_foo(2).
+user:(cp_bar(X, Y) :- cp_foo(X), cp_foo(Y)).
+
+test_queries_on_current_predicate :-
+    findall(N/A, ( current_predicate(N/A), atom_concat(cp_, _, N) ), Ps0),
+    msort(Ps0, Ps),
+    Ps == [cp_bar/2, cp_foo/1],
+    current_predicate(cp_foo/1),
+    \+ current_predicate(cp_foo/2),
+    findall(A, current_predicate(cp_bar/A), As),
+    As == [2],
+    findall(N, current_predicate(N/1), Ns),
+    memberchk(cp_foo, Ns),
+    \+ current_predicate(write/1),
+    catch(current_predicate(cp_foo), error(type_error(predicate_indicator, cp_foo), _), true),
+    catch(current_predicate(1/a), error(type_error(predicate_indicator, 1/a), _), true).
+
+:- initialization(test_queries_on_current_predicate).
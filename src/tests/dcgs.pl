@@ -0,0 +1,69 @@
+:- module(tests_on_dcgs, []).
+
+:- use_module(library(dcgs)).
+:- use_module(library(iso_ext)).
+:- use_module(library(lists)).
+
+greeting --> [hello], [world].
+
+digits([D|Ds]) --> digit(D), digits_(Ds).
+
+digits_([D|Ds]) --> digit(D), !, digits_(Ds).
+digits_([]) --> [].
+
+digit(D) --> [D], { member(D, "0123456789") }.
+
+not_a --> \+ [a], [_].
+
+ab --> ( [a] -> [b] ; [c] ).
+
+z([z|S], S).
+
+test_queries_on_phrase :-
+    phrase(greeting, [hello, world]),
+    \+ phrase(greeting, [hello]),
+    \+ phrase(greeting, [hello, world, again]),
+    phrase(greeting, [hello, world, again], Rest),
+    Rest == [again],
+    phrase(digits(Ds), "123abc", Rest1),
+    Ds == "123",
+    Rest1 == "abc",
+    \+ phrase(digits(_), "abc"),
+    phrase(([x], [y]), [x, y]),
+    phrase(([x] | [y]), [y]),
+    phrase({ X = 1 }, [], []),
+    X == 1,
+    phrase([], []),
+    phrase(seq(Cs), "ab", []),
+    Cs == "ab",
+    findall(Xs-Ys, phrase((seq(Xs), seq(Ys)), [a, b]), Splits),
+    Splits == [[]-[a, b], [a]-[b], [a, b]-[]],
+    phrase(not_a, [b]),
+    \+ phrase(not_a, [a]),
+    phrase(\+ [a], [b], Rest2),
+    Rest2 == [b],
+    phrase(ab, [a, b]),
+    phrase(ab, [c]),
+    \+ phrase(ab, [a, c]),
+    phrase(call(z), [z]),
+    catch(phrase(_, []), error(instantiation_error, _), true),
+    catch(phrase(1, []), error(type_error(callable, 1), _), true).
+
+:- initialization(test_queries_on_phrase).
+
+test_queries_on_dcg_translate_rule :-
+    dcg_translate_rule((a --> [x], { foo }, b), C0),
+    C0 =@= (a(S0, S) :- S0 = [x|S1], ((foo, S1 = S2), b(S2, S))),
+    dcg_translate_rule((p(X), [t] --> q(X)), C1),
+    C1 =@= (p(Y, T0, T) :- q(Y, T0, T1), T = [t|T1]),
+    dcg_translate_rule((r --> []), C2),
+    C2 =@= (r(U0, U) :- U0 = U),
+    dcg_translate_rule((s --> ( [a] -> t ; \+ u )), C3),
+    C3 =@= (s(V0, V) :- ( V0 = [a|V1] -> t(V1, V) ; \+ phrase(u, V0, _), V0 = V )),
+    catch(dcg_translate_rule(_, _), error(instantiation_error, _), true),
+    pushback([a, b], Rest),
+    Rest == [c, b].
+
+pushback, [c] --> [a].
+
+:- initialization(test_queries_on_dcg_translate_rule).
@@ -0,0 +1,26 @@
+:- module(tests_on_dif, []).
+
+:- use_module(library(dif)).
+
+test_queries_on_dif :-
+    dif(X0, Y0),
+    X0 = 1,
+    Y0 = 2,
+    \+ dif(Z0, Z0),
+    dif(a, b),
+    \+ dif(a, a),
+    dif(X1, Y1),
+    \+ X1 = Y1,
+    dif(f(X2, a), f(b, Y2)),
+    X2 = b,
+    \+ Y2 = a,
+    Y2 = c,
+    dif(g(X3), g(Y3)),
+    X3 = 1,
+    \+ Y3 = 1,
+    dif(X4, Y4),
+    copy_term(X4-Y4, C0, Gs0),
+    C0 = X5-Y5,
+    Gs0 = [dif:dif(X5, Y5)|_].
+
+:- initialization(test_queries_on_dif).
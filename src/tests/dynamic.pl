@@ -0,0 +1,121 @@
+:- module(tests_on_dynamic, []).
+
+:- use_module(library(lists)).
+
+:- dynamic(counter/1).
+:- dynamic(edge/2).
+:- dynamic(path/2).
+:- dynamic(item/1).
+:- dynamic(grandparent/2).
+
+test_queries_on_assert :-
+    assertz(counter(1)),
+    counter(N0),
+    N0 == 1,
+    assertz(counter(2)),
+    asserta(counter(0)),
+    findall(N, counter(N), Ns),
+    Ns == [0, 1, 2],
+    assertz(edge(a, b)),
+    assertz(edge(b, c)),
+    assertz((path(X, Y) :- edge(X, Y))),
+    assertz((path(X, Z) :- edge(X, Y), path(Y, Z))),
+    findall(Y, path(a, Y), Ys),
+    Ys == [b, c],
+    asserta(fresh(x)),
+    fresh(F),
+    F == x,
+    \+ \+ ( T = t(V), assertz(shared(T)), V = bound ),
+    shared(S),
+    S = t(V0),
+    var(V0),
+    catch(assertz(_), error(instantiation_error, _), true),
+    B = 1,
+    catch(assertz((foo :- B)), error(type_error(callable, _), _), true),
+    catch(assertz(B), error(type_error(callable, 1), _), true),
+    catch(assertz(atom(x)), error(permission_error(modify, static_procedure, atom/1), _), true).
+
+:- initialization(test_queries_on_assert).
+
+test_queries_on_retract :-
+    assertz(item(1)),
+    assertz(item(2)),
+    assertz(item(3)),
+    findall(X, ( item(X), ( X =:= 1 -> retract(item(2)) ; true ) ), Xs),
+    Xs == [1, 2, 3],
+    findall(Y, item(Y), Ys),
+    Ys == [1, 3],
+    retract((path(_, _) :- edge(_, _), path(_, _))),
+    findall(Z, path(a, Z), Zs),
+    Zs == [b],
+    \+ retract(item(2)),
+    catch(retract(atom(_)), error(permission_error(modify, static_procedure, atom/1), _), true).
+
+:- initialization(test_queries_on_retract).
+
+test_queries_on_clause :-
+    findall(X-Y-B, clause(edge(X, Y), B), Edges),
+    Edges == [a-b-true, b-c-true],
+    findall(X1-Y1, clause(tests_on_dynamic:edge(X1, Y1), true), Edges1),
+    Edges1 == [a-b, b-c],
+    assertz((grandparent(X0, Z0) :- edge(X0, Y0), edge(Y0, Z0))),
+    clause(grandparent(G, H), Body),
+    Body = (edge(G0, I), edge(I0, H0)),
+    G == G0, I == I0, H == H0,
+    \+ clause(edge(c, _), _),
+    catch(clause(_, true), error(instantiation_error, _), true),
+    catch(clause(atom(_), _), error(permission_error(access, private_procedure, atom/1), _), true).
+
+:- initialization(test_queries_on_clause).
+
+test_queries_on_retractall :-
+    assertz(item(4)),
+    assertz(item(5)),
+    retractall(item(4)),
+    findall(X, item(X), Xs),
+    Xs == [1, 3, 5],
+    retractall(item(_)),
+    \+ item(_),
+    retractall(tests_on_dynamic:undeclared(_)),
+    \+ tests_on_dynamic:undeclared(_),
+    predicate_property(tests_on_dynamic:undeclared(_), dynamic),
+    abolish(tests_on_dynamic:grandparent/2),
+    \+ catch(tests_on_dynamic:grandparent(_, _), error(existence_error(_, _), _), false),
+    catch(( retractall(atom(_)), false ),
+          error(permission_error(modify, static_procedure, atom/1), _),
+          true).
+
+:- initialization(test_queries_on_retractall).
+
+wrapped_var(g(_)).
+
+test_queries_on_assert_arity :-
+    length(Args0, 300),
+    Head0 =.. [wide | Args0],
+    assertz(Head0),
+    length(Args1, 300),
+    Goal =.. [wide | Args1],
+    call(Goal),
+    length(Args2, 1000),
+    maplist(wrapped_var, Args2),
+    Head1 =.. [wider | Args2],
+    catch((assertz(Head1), false),
+          error(representation_error(max_arity), assertz/1),
+          true).
+
+:- initialization(test_queries_on_assert_arity).
+
+test_queries_on_predicate_property :-
+    predicate_property(tests_on_dynamic:edge(_, _), dynamic),
+    predicate_property(tests_on_dynamic:edge(_, _), defined),
+    predicate_property(tests_on_dynamic:edge(_, _), number_of_clauses(2)),
+    \+ predicate_property(tests_on_dynamic:edge(_, _), static),
+    findall(P, predicate_property(tests_on_dynamic:edge(_, _), P), Ps),
+    memberchk(number_of_clauses(2), Ps),
+    predicate_property(atom(_), built_in),
+    predicate_property(atom(_), static),
+    predicate_property(atom(_), defined),
+    \+ predicate_property(atom(_), dynamic),
+    \+ predicate_property(atom(_), number_of_clauses(_)).
+
+:- initialization(test_queries_on_predicate_property).
@@ -0,0 +1,46 @@
+:- module(tests_on_error, []).
+
+:- use_module(library(error)).
+
+test_queries_on_must_be :-
+    must_be(integer, 3),
+    catch(must_be(integer, _), error(instantiation_error, _), true),
+    catch(must_be(integer, a), error(type_error(integer, a), _), true),
+    must_be(atom, a),
+    catch(must_be(atom, _), error(instantiation_error, _), true),
+    catch(must_be(atom, f(a)), error(type_error(atom, f(a)), _), true),
+    must_be(callable, f(_)),
+    must_be(callable, a),
+    catch(must_be(callable, _), error(instantiation_error, _), true),
+    catch(must_be(callable, 1), error(type_error(callable, 1), _), true),
+    must_be(list, [a, _]),
+    catch(must_be(list, [a|_]), error(instantiation_error, _), true),
+    catch(must_be(list, [a|b]), error(type_error(list, [a|b]), _), true),
+    must_be(boolean, true),
+    must_be(boolean, false),
+    catch(must_be(boolean, _), error(instantiation_error, _), true),
+    catch(must_be(boolean, yes), error(type_error(boolean, yes), _), true),
+    must_be(positive_integer, 1),
+    catch(must_be(positive_integer, _), error(instantiation_error, _), true),
+    catch(must_be(positive_integer, 0), error(type_error(positive_integer, 0), _), true),
+    catch(must_be(positive_integer, a), error(type_error(integer, a), _), true),
+    must_be(nonneg, 0),
+    must_be(nonneg, 7),
+    catch(must_be(nonneg, _), error(instantiation_error, _), true),
+    catch(must_be(nonneg, -1), error(type_error(nonneg, -1), _), true),
+    catch(must_be(nonneg, 1.0), error(type_error(integer, 1.0), _), true),
+    catch(must_be(_, a), error(instantiation_error, _), true),
+    catch(must_be(foo, a), error(type_error(type, foo), _), true).
+
+:- initialization(test_queries_on_must_be).
+
+test_queries_on_can_be :-
+    can_be(callable, _),
+    can_be(callable, f(x)),
+    can_be(nonneg, _),
+    can_be(nonneg, 0),
+    can_be(positive_integer, 5),
+    catch(can_be(callable, 1), error(type_error(callable, 1), _), true),
+    catch(can_be(nonneg, -3), error(type_error(nonneg, -3), _), true).
+
+:- initialization(test_queries_on_can_be).
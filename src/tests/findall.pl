@@ -0,0 +1,76 @@
+:- module(tests_on_findall, []).
+
+:- use_module(library(between)).
+:- use_module(library(lists)).
+
+p(1, a).
+p(2, b).
+p(3, a).
+
+test_queries_on_findall_4 :-
+    findall(X, p(X, _), L0, []),
+    findall(X, p(X, _), L1),
+    L0 == L1,
+    findall(X, p(X, a), L2, [tail, end]),
+    L2 == [1, 3, tail, end],
+    findall(X, p(X, c), L3, [tail]),
+    L3 == [tail],
+    findall(X, p(X, _), L4, T),
+    L4 = [1, 2, 3|T0],
+    T == T0,
+    var(T),
+    findall(X, p(X, b), [Y|T1], T1),
+    Y == 2,
+    catch(findall(X, p(X, _), _, foo), error(type_error(list, foo), _), true).
+
+:- initialization(test_queries_on_findall_4).
+
+test_queries_on_bagof_setof :-
+    findall(X, p(X, _), L0),
+    L0 == [1, 2, 3],
+    findall(K-L, bagof(X, p(X, K), L), Gs0),
+    Gs0 == [a-[1, 3], b-[2]],
+    bagof(X, K^p(X, K), L1),
+    L1 == [1, 2, 3],
+    \+ bagof(X, p(X, c), _),
+    findall(X, p(X, c), L2),
+    L2 == [],
+    setof(K, X^p(X, K), L3),
+    L3 == [a, b],
+    bagof(K, X^p(X, K), L4),
+    L4 == [a, b, a],
+    findall(X-L, setof(K, p(X, K), L), Gs1),
+    Gs1 == [1-[a], 2-[b], 3-[a]],
+    setof(X-K, p(X, K), L5),
+    L5 == [1-a, 2-b, 3-a],
+    setof(X, member(X, [c, a, b, a]), L6),
+    L6 == [a, b, c],
+    \+ setof(X, fail, _),
+    catch(bagof(_, _, _), error(instantiation_error, _), true),
+    G = 1,
+    catch(setof(_, G, _), error(type_error(callable, 1), _), true).
+
+:- initialization(test_queries_on_bagof_setof).
+
+test_queries_on_findall_ground_templates :-
+    numlist(1, 1000, Ns),
+    findall(X-Ns, member(X, [a, b, c]), Sols0),
+    Sols0 = [a-Ns0, b-Ns1, c-Ns2],
+    Ns0 == Ns,
+    Ns1 == Ns,
+    Ns2 == Ns,
+    findall(f(Ns, V), member(_, [1, 2]), [f(Ns3, V0), f(Ns4, V1)]),
+    V0 = x,
+    var(V1),
+    Ns3 == Ns,
+    Ns4 == Ns,
+    Ls0 = [A, b],
+    findall(Ls0, member(A, [1, 2]), Sols1),
+    Sols1 == [[1, b], [2, b]],
+    var(A),
+    findall(Ys, member(Ys, [Ns, [x], Ns]), Sols2),
+    Sols2 == [Ns, [x], Ns],
+    findall(Ns-Zs, (member(Z, [1, 2]), findall(Z-Ns, member(_, [a, b]), Zs)), Sols3),
+    Sols3 == [Ns-[1-Ns, 1-Ns], Ns-[2-Ns, 2-Ns]].
+
+:- initialization(test_queries_on_findall_ground_templates).
@@ -0,0 +1,56 @@
+:- module(tests_on_format, []).
+
+:- use_module(library(format)).
+:- use_module(library(iso_ext)).
+:- use_module(library(dcgs)).
+
+test_queries_on_format :-
+    with_output_to(string(S0), format("~w-~d~n", [foo, 42])),
+    S0 == "foo-42\n",
+    with_output_to(string(S1), format('~a ~~ ~q', [abc, 'A b'])),
+    S1 == "abc ~ 'A b'",
+    with_output_to(string(S2), format([0'~, 0'w, 0'!], [x])),
+    S2 == "x!",
+    phrase(format_("~p", ['$VAR'(1)]), S3),
+    S3 == "B",
+    phrase(format_("~p", ['A']), S4),
+    S4 == "'A'",
+    phrase(format_("~w~w", [f(X, _), X]), S5),
+    S5 == "f(A,B)A",
+    assertz(user:(portray(secret(_)) :- write('<hidden>'))),
+    phrase(format_("~p and ~p", [secret(42), public(42)]), S6),
+    S6 == "<hidden> and public(42)",
+    with_output_to(string(S7), format("~p", [secret(42)])),
+    S7 == "<hidden>",
+    retractall(user:portray(_)),
+    catch(format("~d", [a]), error(type_error(evaluable, a/0), _), true),
+    catch(format("~w", []), error(_, _), true).
+
+:- initialization(test_queries_on_format).
+
+test_queries_on_print_message :-
+    assertz(user:(message(test_message(N), Ls0, Ls) :-
+                      Ls0 = ["value: ~d"-[N], nl, "done"|Ls])),
+    assertz(user:(message_hook(_, Kind, Lines) :-
+                      assertz(captured_message(Kind, Lines)))),
+    print_message(warning, test_message(42)),
+    user:captured_message(K0, L0),
+    K0 == warning,
+    L0 == ["value: ~d"-[42], nl, "done"],
+    retractall(user:captured_message(_, _)),
+    print_message(informational, format("~w and ~w", [a, b])),
+    user:captured_message(K1, L1),
+    K1 == informational,
+    L1 == ["~w and ~w"-[a, b]],
+    retractall(user:captured_message(_, _)),
+    print_message(error, foo(bar)),
+    user:captured_message(_, L2),
+    L2 == ["~q"-[foo(bar)]],
+    retractall(user:captured_message(_, _)),
+    phrase(format:message_chars(L0, "Warning: "), Cs),
+    Cs == "Warning: value: 42\nWarning: done\n",
+    catch(print_message(_, foo), error(instantiation_error, _), true),
+    retractall(user:message(_, _, _)),
+    retractall(user:message_hook(_, _, _)).
+
+:- initialization(test_queries_on_print_message).
@@ -0,0 +1,24 @@
+:- module(tests_on_freeze, []).
+
+:- use_module(library(freeze)).
+
+test_queries_on_freeze :-
+    freeze(X0, X0 > 0),
+    X0 = 5,
+    \+ ( freeze(X1, fail), X1 = 1 ),
+    freeze(X2, A = 1),
+    freeze(X2, B = 2),
+    var(A),
+    var(B),
+    X2 = a,
+    A == 1,
+    B == 2,
+    freeze(X3, C = done),
+    X3 = Y3,
+    var(C),
+    Y3 = b,
+    C == done,
+    freeze(c, D = now),
+    D == now.
+
+:- initialization(test_queries_on_freeze).
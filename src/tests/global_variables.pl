@@ -0,0 +1,51 @@
+:- module(tests_on_global_variables, []).
+
+:- use_module(library(iso_ext)).
+:- use_module(library(lists)).
+
+test_queries_on_nb_setval :-
+    nb_setval(test_counter, 0),
+    nb_getval(test_counter, V0),
+    V0 == 0,
+    (  member(X, [1, 2, 3]),
+       nb_getval(test_counter, C0),
+       C is C0 + X,
+       nb_setval(test_counter, C),
+       fail
+    ;  true
+    ),
+    nb_getval(test_counter, V1),
+    V1 == 6,
+    nb_setval(test_term, f(Y, Y, "ab")),
+    nb_getval(test_term, T0),
+    T0 = f(A, B, S),
+    A == B,
+    A \== Y,
+    S == "ab",
+    \+ nb_getval(test_term, g(_)),
+    catch(nb_getval(test_unset_key, _), error(existence_error(variable, test_unset_key), _), true),
+    catch(nb_setval(_, 1), error(instantiation_error, _), true),
+    catch(nb_getval(1, _), error(type_error(atom, 1), _), true).
+
+:- initialization(test_queries_on_nb_setval).
+
+test_queries_on_b_setval :-
+    b_setval(test_level, outer),
+    (  b_setval(test_level, inner),
+       b_getval(test_level, V0),
+       V0 == inner,
+       fail
+    ;  b_getval(test_level, V1),
+       V1 == outer
+    ),
+    (  b_setval(test_fresh_key, 1),
+       fail
+    ;  catch(b_getval(test_fresh_key, _), error(existence_error(variable, test_fresh_key), _), true)
+    ),
+    findall(V, (member(X, [a, b]), b_setval(test_level, X), b_getval(test_level, V)), Vs),
+    Vs == [a, b],
+    b_getval(test_level, V2),
+    V2 == outer,
+    catch(b_setval(_, 1), error(instantiation_error, _), true).
+
+:- initialization(test_queries_on_b_setval).
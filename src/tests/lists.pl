@@ -0,0 +1,273 @@
+:- module(tests_on_lists, []).
+
+:- use_module(library(arithmetic)).
+:- use_module(library(iso_ext)).
+:- use_module(library(lists)).
+
+test_queries_on_nth :-
+    nth0(0, [a, b, c], E0),
+    E0 == a,
+    nth0(2, [a, b, c], E1),
+    E1 == c,
+    nth1(1, [a, b, c], E2),
+    E2 == a,
+    nth1(3, [a, b, c], E3),
+    E3 == c,
+    \+ nth0(3, [a, b, c], _),
+    \+ nth1(0, [a, b, c], _),
+    \+ nth1(4, [a, b, c], _),
+    \+ nth0(-1, [a, b, c], _),
+    \+ nth1(-1, [a, b, c], _),
+    findall(I-E, nth0(I, [a, b, c], E), Ps0),
+    Ps0 == [0-a, 1-b, 2-c],
+    findall(I-E, nth1(I, [a, b, c], E), Ps1),
+    Ps1 == [1-a, 2-b, 3-c],
+    findall(I, nth1(I, [a, b, a], a), Is),
+    Is == [1, 3],
+    nth1(2, L, x),
+    L = [_, X|_],
+    X == x,
+    catch(nth1(a, [a], _), error(type_error(integer, a), _), true).
+
+:- initialization(test_queries_on_nth).
+
+test_queries_on_last_and_sum_list :-
+    last([1, 2, 3], X0),
+    X0 == 3,
+    last([a], X1),
+    X1 == a,
+    \+ last([], _),
+    last([1, 2, 3], 3),
+    \+ last([1, 2, 3], 2),
+    sum_list([1, 2.5, 3], S0),
+    S0 == 6.5,
+    sum_list([1, 2, 3], S1),
+    S1 == 6,
+    sum_list([], S2),
+    S2 == 0,
+    catch(sum_list([1, a], _), error(type_error(evaluable, a/0), _), true),
+    catch(sum_list([1, _], _), error(instantiation_error, _), true).
+
+:- initialization(test_queries_on_last_and_sum_list).
+
+test_queries_on_extrema :-
+    max_list([1, 2.0, 3], Max0),
+    Max0 == 3,
+    max_list([1, 4.0, 3], Max1),
+    Max1 == 4.0,
+    min_list([3, 1, 2], Min0),
+    Min0 == 1,
+    min_list([3, 0.5, 2], Min1),
+    Min1 == 0.5,
+    \+ max_list([], _),
+    \+ min_list([], _),
+    catch(max_list([1, foo], _), error(type_error(evaluable, foo/0), _), true),
+    max_member(M0, [1, a, 2.0]),
+    M0 == a,
+    max_member(M1, [3, f(x), b]),
+    M1 == f(x),
+    min_member(M2, [3, f(x), b]),
+    M2 == 3,
+    min_member(M3, [b, 2, 1.0]),
+    M3 == 1.0,
+    max_member(M4, [1, 2.0, 3]),
+    M4 == 3,
+    \+ max_member(_, []),
+    \+ min_member(_, []).
+
+:- initialization(test_queries_on_extrema).
+
+test_queries_on_foldl :-
+    foldl(plus_, [1, 2, 3], 0, S0),
+    S0 == 6,
+    foldl(plus_, [], 0, S1),
+    S1 == 0,
+    foldl(dot_, [1, 2, 3], [4, 5, 6], 0, D),
+    D == 32,
+    foldl(sum3_, [1, 2], [3, 4], [5, 6], 0, S2),
+    S2 == 21,
+    \+ foldl(dot_, [1, 2], [3], 0, _),
+    \+ foldl(sum3_, [1], [2], [], 0, _),
+    foldl(cons_, [a, b, c], [], R),
+    R == [c, b, a].
+
+plus_(E, A0, A) :- A is A0 + E.
+
+dot_(X, Y, A0, A) :- A is A0 + X * Y.
+
+sum3_(X, Y, Z, A0, A) :- A is A0 + X + Y + Z.
+
+cons_(E, Es, [E|Es]).
+
+:- initialization(test_queries_on_foldl).
+
+test_queries_on_maplist :-
+    maplist(succ, [1, 2, 3], L0),
+    L0 == [2, 3, 4],
+    maplist(succ, L1, [1, 2, 3]),
+    L1 == [0, 1, 2],
+    maplist(integer, []),
+    \+ maplist(integer, [1, a]),
+    \+ maplist(succ, [1, 2, 3], [_, _]),
+    \+ maplist(succ, [1], [2, 3]),
+    maplist(plus, [1, 2], [10, 20], L2),
+    L2 == [11, 22],
+    maplist(dot_, [1, 2], [3, 4], [0, 3], L3),
+    L3 == [3, 11],
+    maplist(=(x), L4),
+    L4 == [],
+    length(L5, 2),
+    maplist(=(y), L5),
+    L5 == [y, y],
+    maplist(succ, [1, 2|T], [2, 3, 4]),
+    T == [3].
+
+:- initialization(test_queries_on_maplist).
+
+test_queries_on_permutation :-
+    findall(P, permutation([1, 2, 3], P), Ps),
+    Ps == [[1, 2, 3], [1, 3, 2], [2, 1, 3], [2, 3, 1], [3, 1, 2], [3, 2, 1]],
+    findall(Q, permutation([a, b, c, d], Q), Qs),
+    length(Qs, 24),
+    findall(R, permutation([], R), Rs),
+    Rs == [[]],
+    permutation(Xs, [x, y]),
+    Xs == [x, y],
+    \+ permutation([1, 2], [1, 2, 3]).
+
+:- initialization(test_queries_on_permutation).
+
+test_queries_on_select :-
+    findall(X-Rest, select(X, [a, b, a], Rest), Sols),
+    Sols == [a-[b, a], b-[a, a], a-[a, b]],
+    findall(Rest1, select(a, [a, b, a], Rest1), Rests),
+    Rests == [[b, a], [a, b]],
+    findall(L, select(x, L, [1, 2]), Ls),
+    Ls == [[x, 1, 2], [1, x, 2], [1, 2, x]],
+    \+ select(c, [a, b], _),
+    findall(Rest2, selectchk(a, [a, b, a], Rest2), Rests2),
+    Rests2 == [[b, a]],
+    selectchk(Y, [p, q], Rest3),
+    Y == p,
+    Rest3 == [q],
+    \+ selectchk(c, [a, b], _).
+
+:- initialization(test_queries_on_select).
+
+positive(X) :- X > 0.
+
+test_queries_on_include_exclude :-
+    include(positive, [3, -1, 0, 7, -5], Is),
+    Is == [3, 7],
+    exclude(positive, [3, -1, 0, 7, -5], Es),
+    Es == [-1, 0, -5],
+    partition(positive, [3, -1, 0, 7, -5], Ps, Ns),
+    Ps == [3, 7],
+    Ns == [-1, 0, -5],
+    include(positive, [], Is1),
+    Is1 == [],
+    partition(positive, [], Ps1, Ns1),
+    Ps1 == [],
+    Ns1 == [],
+    findall(I, include(positive, [1, 2], I), Sols),
+    Sols == [[1, 2]],
+    include(<(1), [0, 1, 2, 3], Gs),
+    Gs == [2, 3].
+
+:- initialization(test_queries_on_include_exclude).
+
+test_queries_on_length :-
+    length([a, b, c], N0),
+    N0 == 3,
+    length("abcd", N1),
+    N1 == 4,
+    length([], N2),
+    N2 == 0,
+    length(L0, 3),
+    L0 = [A, B, C],
+    var(A), var(B), var(C),
+    A \== B,
+    length([a|L1], 3),
+    L1 = [_, _],
+    length([a, b|T], N3),
+    N3 == 2,
+    T == [],
+    findall(N, (length(L2, N), (N >= 3, ! ; true)), Ns),
+    Ns == [0, 1, 2, 3],
+    findall(L3, (length(L3, N4), N4 >= 2, !), Ls),
+    Ls = [[_, _]],
+    length(L4, N5), !,
+    L4 == [],
+    N5 == 0,
+    \+ length([a, b], 3),
+    \+ length(foo, _),
+    catch(length(_, -1), error(domain_error(not_less_than_zero, -1), _), true),
+    catch(length(_, a), error(type_error(integer, a), _), true).
+
+:- initialization(test_queries_on_length).
+
+test_queries_on_append :-
+    append([a, b], [c], L0),
+    L0 == [a, b, c],
+    append([], [c], L1),
+    L1 == [c],
+    append([a], T, L2),
+    L2 = [a|T0],
+    T0 == T,
+    findall(X-Y, append(X, Y, [a, b, c]), Splits),
+    Splits == [[]-[a, b, c], [a]-[b, c], [a, b]-[c], [a, b, c]-[]],
+    append(X1, [c], [a, b, c]),
+    X1 == [a, b],
+    findall(X2, append(X2, [c], [a, b, c]), X2s),
+    X2s == [[a, b]],
+    append([a, b], Y1, [a, b, c, d]),
+    Y1 == [c, d],
+    \+ append(_, [x], [a, b, c]),
+    \+ append([b], _, [a, b]),
+    append("ab", "cd", L3),
+    L3 == "abcd",
+    append([[a], [], [b, c]], L4),
+    L4 == [a, b, c].
+
+:- initialization(test_queries_on_append).
+
+test_queries_on_member :-
+    findall(X, member(X, [a, b, c]), Xs),
+    Xs == [a, b, c],
+    \+ member(d, [a, b, c]),
+    member(b, [a, b, c]),
+    findall(Y, member(Y, "xy"), Ys),
+    Ys == "xy",
+    findall(L0, (call_nth(member(z, L0), N), (N >= 3, ! ; true)), Ls),
+    Ls = [[z|T0], [_, z|T1], [_, _, z|T2]],
+    var(T0), var(T1), var(T2),
+    findall(a, memberchk(a, [a, b, a]), As),
+    As == [a],
+    memberchk(f(Z), [g(1), f(2), f(3)]),
+    Z == 2,
+    \+ memberchk(d, [a, b, c]),
+    memberchk(q, L1),
+    L1 = [q|_].
+
+:- initialization(test_queries_on_member).
+
+test_queries_on_reverse :-
+    reverse([a, b, c], R0),
+    R0 == [c, b, a],
+    reverse([], R1),
+    R1 == [],
+    reverse(L0, [a, b, c]),
+    L0 == [c, b, a],
+    findall(L1, reverse(L1, [1, 2]), L1s),
+    L1s == [[2, 1]],
+    reverse("abc", R2),
+    R2 == "cba",
+    reverse([X, Y], [1, 2]),
+    X == 2,
+    Y == 1,
+    \+ reverse([a, b], [a, b]),
+    \+ reverse([a, b, c], [c, b]),
+    \+ reverse([_, _], [_, _, _]),
+    \+ reverse([a|_], [b, c, d]).
+
+:- initialization(test_queries_on_reverse).
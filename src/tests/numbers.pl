@@ -0,0 +1,137 @@
+:- module(tests_on_numbers, []).
+
+:- use_module(library(arithmetic)).
+:- use_module(library(charsio)).
+
+test_queries_on_numbers :-
+    number_codes(3.14, Cs0),
+    Cs0 == [0'3, 0'., 0'1, 0'4],
+    number_codes(F0, [0'3, 0'., 0'1, 0'4]),
+    F0 == 3.14,
+    number_chars(0.1, Chs0),
+    number_chars(F1, Chs0),
+    F1 == 0.1,
+    number_chars(N0, "123456789012345678901234567890"),
+    N0 =:= 123456789012345678901234567890,
+    number_codes(N0, Cs1),
+    number_codes(N1, Cs1),
+    N1 == N0,
+    number_chars(-17, Chs1),
+    Chs1 == ['-', '1', '7'],
+    number_chars(N2, " 42"),
+    N2 == 42,
+    R0 is 1 rdiv 3,
+    number_chars(R0, Chs2),
+    Chs2 == "1 rdiv 3",
+    number_chars(R1, Chs2),
+    R1 =:= R0,
+    number_codes(R2, [0'-, 0'2, 32, 0'r, 0'd, 0'i, 0'v, 32, 0'6]),
+    R2 =:= -1 rdiv 3,
+    catch(number_chars(_, "1a"), error(syntax_error(_), _), true),
+    catch(number_chars(_, "1 "), error(syntax_error(_), _), true),
+    catch(number_chars(_, "foo"), error(syntax_error(_), _), true),
+    catch(( number_chars(_, "1 rdiv 0"), false ),
+          error(evaluation_error(zero_divisor), _),
+          true),
+    catch(number_codes(_, [0'1, foo]), error(type_error(integer, foo), _), true),
+    catch(number_codes(_, [0'1, -1]), error(representation_error(character_code), _), true),
+    catch(number_chars(a, _), error(type_error(number, a), _), true),
+    catch(number_chars(_, [_|_]), error(instantiation_error, _), true).
+
+test_queries_on_succ :-
+    succ(3, Y0),
+    Y0 == 4,
+    succ(X0, 4),
+    X0 == 3,
+    succ(0, 1),
+    \+ succ(_, 0),
+    \+ succ(3, 5),
+    succ(9223372036854775807, Y1),
+    Y1 =:= 9223372036854775808,
+    succ(X1, Y1),
+    X1 =:= 9223372036854775807,
+    catch(succ(a, _), error(type_error(integer, a), _), true),
+    catch(succ(_, 1.0), error(type_error(integer, 1.0), _), true),
+    catch(succ(-1, _), error(type_error(not_less_than_zero, -1), _), true),
+    catch(succ(_, -1), error(type_error(not_less_than_zero, -1), _), true),
+    catch(succ(_, _), error(instantiation_error, _), true).
+
+test_queries_on_plus :-
+    plus(2, 3, C0),
+    C0 == 5,
+    plus(2, B0, 5),
+    B0 == 3,
+    plus(A0, 3, 5),
+    A0 == 2,
+    plus(-2, 3, 1),
+    \+ plus(2, 3, 6),
+    plus(9223372036854775807, 1, C1),
+    C1 =:= 9223372036854775808,
+    plus(A1, 1, C1),
+    A1 =:= 9223372036854775807,
+    catch(plus(a, 1, _), error(type_error(integer, a), _), true),
+    catch(plus(1, _, 2.0), error(type_error(integer, 2.0), _), true),
+    catch(plus(_, _, 5), error(instantiation_error, _), true),
+    catch(plus(1, _, _), error(instantiation_error, _), true).
+
+test_queries_on_atom_number :-
+    atom_number('42', N0),
+    N0 == 42,
+    atom_number('3.0', N1),
+    N1 == 3.0,
+    atom_number('-7', N2),
+    N2 == -7,
+    atom_number('0x1F', N3),
+    N3 == 31,
+    atom_number('1 rdiv 3', N4),
+    N4 =:= 1 rdiv 3,
+    \+ atom_number(foo, _),
+    \+ atom_number('', _),
+    \+ atom_number('1a', _),
+    atom_number(A0, 12),
+    A0 == '12',
+    atom_number(A1, 0.1),
+    A1 == '0.1',
+    R is 2 rdiv 4,
+    atom_number(A2, R),
+    A2 == '1 rdiv 2',
+    catch(atom_number(_, _), error(instantiation_error, _), true),
+    catch(atom_number(_, a), error(type_error(number, a), _), true),
+    catch(atom_number(12, _), error(type_error(atom, 12), _), true).
+
+test_queries_on_number_string :-
+    number_string(N0, "3.14"),
+    N0 == 3.14,
+    number_string(N1, "-42"),
+    N1 == -42,
+    number_string(3.14, S0),
+    S0 == "3.14",
+    number_string(0.1, S1),
+    S1 == "0.1",
+    number_string(12, S2),
+    S2 == "12",
+    \+ number_string(_, "foo"),
+    \+ number_string(_, ""),
+    \+ number_string(_, "1a"),
+    \+ number_string(2, "3"),
+    catch(number_string(_, _), error(instantiation_error, _), true),
+    catch(number_string(a, _), error(type_error(number, a), _), true).
+
+test_queries_on_is_errors :-
+    catch(_ is foo, error(E0, _), true),
+    E0 == type_error(evaluable, foo/0),
+    catch(_ is foo(1), error(E1, _), true),
+    E1 == type_error(evaluable, foo/1),
+    catch(_ is 1 + bar(1, 2), error(E2, _), true),
+    E2 == type_error(evaluable, bar/2),
+    catch(_ is 1 + a, error(E3, _), true),
+    E3 == type_error(evaluable, a/0),
+    catch(_ is _ + 1, error(E4, _), true),
+    E4 == instantiation_error.
+
+:- initialization(test_queries_on_numbers).
+:- initialization(test_queries_on_succ).
+:- initialization(test_queries_on_plus).
+:- initialization(test_queries_on_atom_number).
+:- initialization(test_queries_on_number_string).
+:- initialization(test_queries_on_is_errors).
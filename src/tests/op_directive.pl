@@ -0,0 +1,16 @@
+:- module(tests_on_op_directive, []).
+
+:- op(700, xfx, ===).
+:- op(200, xfy, [++, --]).
+
+X === X.
+
+chain(a ++ b -- c).
+
+test_queries_on_op_directive :-
+    a === a,
+    \+ a === b,
+    chain(T),
+    T == ++(a, --(b, c)).
+
+:- initialization(test_queries_on_op_directive).
@@ -0,0 +1,80 @@
+:- module(tests_on_prolog_flags, []).
+
+:- use_module(library(iso_ext)).
+:- use_module(library(lists)).
+
+test_queries_on_double_quotes_flag :-
+    current_prolog_flag(double_quotes, DQ0),
+    DQ0 == chars,
+    set_prolog_flag(double_quotes, codes),
+    current_prolog_flag(double_quotes, DQ1),
+    DQ1 == codes,
+    set_prolog_flag(double_quotes, atom),
+    current_prolog_flag(double_quotes, DQ2),
+    DQ2 == atom,
+    set_prolog_flag(double_quotes, chars),
+    current_prolog_flag(double_quotes, DQ3),
+    DQ3 == chars,
+    catch(set_prolog_flag(double_quotes, bytes),
+          error(domain_error(flag_value, double_quotes + bytes), _),
+          true).
+
+test_queries_on_unknown_flag :-
+    current_prolog_flag(unknown, U0),
+    U0 == error,
+    set_prolog_flag(unknown, fail),
+    current_prolog_flag(unknown, U1),
+    U1 == fail,
+    \+ call(no_such_procedure),
+    set_prolog_flag(unknown, warning),
+    current_prolog_flag(unknown, U2),
+    U2 == warning,
+    with_output_to(string(S), \+ call(no_such_procedure)),
+    S == "Warning: unknown procedure no_such_procedure/0\n",
+    set_prolog_flag(unknown, error),
+    catch(call(no_such_procedure),
+          error(existence_error(procedure, no_such_procedure/0), _),
+          true),
+    catch(set_prolog_flag(unknown, maybe),
+          error(domain_error(flag_value, unknown + maybe), _),
+          true).
+
+test_queries_on_read_only_flags :-
+    current_prolog_flag(bounded, false),
+    current_prolog_flag(max_integer, Max),
+    integer(Max),
+    current_prolog_flag(min_integer, Min),
+    Min < 0,
+    catch(set_prolog_flag(bounded, true),
+          error(permission_error(modify, flag, bounded), _),
+          true),
+    catch(set_prolog_flag(max_integer, 0),
+          error(permission_error(modify, flag, max_integer), _),
+          true),
+    catch(set_prolog_flag(occurs_check, maybe),
+          error(domain_error(flag_value, occurs_check + maybe), _),
+          true),
+    catch(set_prolog_flag(no_such_flag, true),
+          error(domain_error(prolog_flag, no_such_flag), _),
+          true).
+
+test_queries_on_integer_flags :-
+    current_prolog_flag(bounded, Bounded),
+    Bounded == false,
+    current_prolog_flag(max_integer, Max),
+    integer(Max),
+    Max > 0,
+    current_prolog_flag(min_integer, Min),
+    Min =:= -Max - 1,
+    Max1 is Max + 1,
+    Max1 > Max,
+    Min1 is Min - 1,
+    Min1 < Min,
+    findall(F, ( current_prolog_flag(F, _),
+                 member(F, [bounded, max_integer, min_integer]) ), Fs),
+    Fs == [bounded, max_integer, min_integer].
+
+:- initialization(test_queries_on_double_quotes_flag).
+:- initialization(test_queries_on_unknown_flag).
+:- initialization(test_queries_on_read_only_flags).
+:- initialization(test_queries_on_integer_flags).
@@ -0,0 +1,47 @@
+:- module(tests_on_read_number, []).
+
+:- use_module(library(charsio)).
+
+test_queries_on_read_number :-
+    open_chars_stream("3.14rest", S0),
+    read_number(S0, N0),
+    N0 == 3.14,
+    read_string(S0, 4, Cs0),
+    Cs0 == "rest",
+    close(S0),
+    open_chars_stream("42.x", S1),
+    read_number(S1, N1),
+    N1 == 42,
+    read_string(S1, 2, Cs1),
+    Cs1 == ".x",
+    close(S1),
+    open_chars_stream("1.5e+x", S2),
+    read_number(S2, N2),
+    N2 == 1.5,
+    read_string(S2, 3, Cs2),
+    Cs2 == "e+x",
+    close(S2),
+    open_chars_stream("0xffg", S3),
+    read_number(S3, N3),
+    N3 == 255,
+    read_string(S3, 1, Cs3),
+    Cs3 == "g",
+    close(S3),
+    open_chars_stream("0xg", S4),
+    read_number(S4, N4),
+    N4 == 0,
+    read_string(S4, 2, Cs4),
+    Cs4 == "xg",
+    close(S4),
+    open_chars_stream("123", S5),
+    read_number(S5, N5),
+    N5 == 123,
+    \+ read_string(S5, 1, _),
+    close(S5),
+    open_chars_stream("abc", S6),
+    \+ read_number(S6, _),
+    read_string(S6, 3, Cs6),
+    Cs6 == "abc",
+    close(S6).
+
+:- initialization(test_queries_on_read_number).
@@ -0,0 +1,32 @@
+:- module(tests_on_read_string, []).
+
+:- use_module(library(charsio)).
+
+test_queries_on_read_string :-
+    open_chars_stream("héllo wörld", S0),
+    read_string(S0, 5, Cs0),
+    Cs0 == "héllo",
+    read_string(S0, 1, Cs1),
+    Cs1 == " ",
+    read_string(S0, 0, Cs2),
+    Cs2 == [],
+    read_string(S0, 5, Cs3),
+    Cs3 == "wörld",
+    \+ read_string(S0, 1, _),
+    close(S0),
+    open_chars_stream("ab", S1),
+    \+ read_string(S1, 3, _),
+    close(S1),
+    open_chars_stream("éöü", S2),
+    read_string(S2, 1, Cs4),
+    Cs4 == "é",
+    read_string(S2, 2, Cs5),
+    Cs5 == "öü",
+    close(S2),
+    open_chars_stream("abc", S3),
+    catch(read_string(S3, -1, _), error(domain_error(not_less_than_zero, -1), _), true),
+    catch(read_string(S3, a, _), error(type_error(integer, a), _), true),
+    catch(read_string(S3, _, _), error(instantiation_error, _), true),
+    close(S3).
+
+:- initialization(test_queries_on_read_string).
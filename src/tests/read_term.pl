@@ -0,0 +1,153 @@
+:- module(tests_on_read_term, []).
+
+:- use_module(library(charsio)).
+:- use_module(library(iso_ext)).
+:- use_module(library(lists)).
+
+test_queries_on_read_term :-
+    \+ \+ ( read_term_from_chars("f(X, Y, Y).", T,
+                                 [singletons(Ss), variables(Vs), variable_names(VNs)]),
+            T = f(X, Y, Y0),
+            Y == Y0,
+            Ss == ['X'=X],
+            Vs == [X, Y],
+            VNs == ['X'=X, 'Y'=Y] ),
+    \+ \+ ( read_term_from_chars("g(_, _A).", T, [singletons(Ss)]),
+            T = g(_, A),
+            Ss == ['_A'=A] ),
+    \+ \+ ( read_term_from_chars("h(X, X).", T, [singletons(Ss)]),
+            Ss == [] ),
+    catch(read_term_from_chars("a.", _, [bad_option]),
+          error(domain_error(read_option, bad_option), _),
+          true),
+    \+ \+ ( T = f(_, 'has space', 1+2),
+            term_to_atom(T, A),
+            term_to_atom(T0, A),
+            variant(T, T0) ),
+    term_to_atom(f('A', - (1), 'has space', a- (-1), [x|y]), A0),
+    A0 == 'f(\'A\',- (1),\'has space\',a- -1,[x|y])',
+    term_to_atom(1+2*3, '1+2*3'),
+    \+ term_to_atom(1+2, '1 + 3'),
+    \+ \+ ( atom_to_term('foo(X, Y, X)', T, Bs),
+            T = foo(X, Y, X0),
+            X == X0,
+            Bs == ['X'=X, 'Y'=Y] ),
+    catch(atom_to_term(_, _, _), error(instantiation_error, _), true),
+    catch(atom_to_term("f", _, _), error(type_error(atom, _), _), true).
+
+test_queries_on_read_clause :-
+    open_chars_stream("foo(X, [a|T]) :-\n    bar(X),\n    T = b.\n", S),
+    read_clause(S, Clause, [layout(Layout)]),
+    close(S),
+    Clause = (foo(X, [a|T]) :- bar(X), T = b),
+    Layout == span(0, 0, 2, 9,
+                   [span(0, 0, 0, 13,
+                         [span(0, 4, 0, 5, []),
+                          span(0, 7, 0, 12,
+                               [span(0, 8, 0, 9, []),
+                                span(0, 10, 0, 11, [])])]),
+                    span(1, 4, 2, 9,
+                         [span(1, 4, 1, 10,
+                               [span(1, 8, 1, 9, [])]),
+                          span(2, 4, 2, 9,
+                               [span(2, 4, 2, 5, []),
+                                span(2, 8, 2, 9, [])])])]),
+    open_chars_stream("{a} - -1.", S0),
+    read_clause(S0, Clause0, [layout(Layout0)]),
+    close(S0),
+    Clause0 == {a} - -1,
+    Layout0 == span(0, 0, 0, 8,
+                    [span(0, 0, 0, 3, [span(0, 1, 0, 2, [])]),
+                     span(0, 6, 0, 8, [])]),
+    read_term_from_chars("f(a).", Term1, [layout(Layout1)]),
+    Term1 == f(a),
+    Layout1 == span(0, 0, 0, 4, [span(0, 2, 0, 3, [])]),
+    catch(read_clause(user_input, _, [layout]),
+          error(domain_error(read_option, layout), _),
+          true).
+
+test_queries_on_syntax_error_positions :-
+    open_chars_stream("foo(a,\n    \x01\).", S0),
+    catch((read_term(S0, _, []), false),
+          error(syntax_error(unexpected_char), read_term/3:position(1, 4)),
+          true),
+    close(S0),
+    open_chars_stream("foo(a,\n    b", S1),
+    catch((read_term(S1, _, []), false),
+          error(syntax_error(unexpected_end_of_file), read_term/3:position(1, 5)),
+          true),
+    close(S1),
+    % the lexer reads ahead of "1" and "." and puts the dot back both
+    % times, so the offending character is still found at its column.
+    open_chars_stream("f(1.\x01\).", S2),
+    catch((read_term(S2, _, []), false),
+          error(syntax_error(unexpected_char), read_term/3:position(0, 4)),
+          true),
+    close(S2).
+
+test_queries_on_max_term_depth :-
+    length(Opens, 2000),
+    maplist(=('('), Opens),
+    append(Opens, "a.", Cs),
+    length(Elems, 5000),
+    maplist(=(a), Elems),
+    term_string(Elems, Es),
+    append(Es, ".", Es1),
+    current_prolog_flag(max_term_depth, Max),
+    setup_call_cleanup(set_prolog_flag(max_term_depth, 1000),
+                       ( open_chars_stream(Cs, S0),
+                         catch((read_term(S0, _, []), false),
+                               error(resource_error(term_depth), read_term/3),
+                               true),
+                         close(S0),
+                         open_chars_stream("f(a).", S1),
+                         read_term(S1, T, []),
+                         close(S1),
+                         T == f(a),
+                         % long flat terms are not limited.
+                         open_chars_stream(Es1, S2),
+                         read_term(S2, L, []),
+                         close(S2),
+                         L == Elems ),
+                       set_prolog_flag(max_term_depth, Max)),
+    current_prolog_flag(max_term_depth, Max),
+    catch(set_prolog_flag(max_term_depth, 0),
+          error(domain_error(flag_value, max_term_depth + 0), _),
+          true).
+
+test_queries_on_term_string :-
+    term_string(f('A', "s", - (1), a- (-1), [x|y], 1+2*3, (a:-b,c;d)), S0),
+    S0 == "f('A',\"s\",- (1),a- -1,[x|y],1+2*3,(a:-b,c;d))",
+    term_string(T0, S0),
+    T0 == f('A', "s", - (1), a- (-1), [x|y], 1+2*3, (a:-b,c;d)),
+    \+ \+ ( term_string(g(X, Y, X), S1),
+            term_string(T1, S1),
+            variant(T1, g(X, Y, X)) ),
+    term_string(T2, "foo(A, B, A)"),
+    T2 = foo(A, B, A0),
+    A == A0,
+    A \== B,
+    \+ term_string(1+2, "1 + 3"),
+    catch(term_string(_, [a|_]), error(instantiation_error, _), true),
+    catch(term_string(_, "f("), error(syntax_error(_), _), true).
+
+test_queries_on_read_term_from_atom :-
+    \+ \+ ( read_term_from_atom('foo(X, Y)', T, [variable_names(VNs)]),
+            T = foo(X, Y),
+            VNs == ['X'=X, 'Y'=Y] ),
+    \+ \+ ( read_term_from_atom('bar(X, _Y, Z, Z)', T, [singletons(Ss)]),
+            T = bar(X, Y, _, _),
+            Ss == ['X'=X, '_Y'=Y] ),
+    read_term_from_atom('a:-b', T0, []),
+    T0 == (a :- b),
+    \+ read_term_from_atom(g, h, []),
+    catch(read_term_from_atom('foo(', _, []), error(syntax_error(_), _), true),
+    catch(read_term_from_atom(_, _, []), error(instantiation_error, _), true),
+    catch(read_term_from_atom("f", _, []), error(type_error(atom, _), _), true).
+
+:- initialization(test_queries_on_read_term).
+:- initialization(test_queries_on_read_clause).
+:- initialization(test_queries_on_syntax_error_positions).
+:- initialization(test_queries_on_max_term_depth).
+:- initialization(test_queries_on_read_term_from_atom).
+:- initialization(test_queries_on_term_string).
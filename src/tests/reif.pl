@@ -0,0 +1,30 @@
+:- module(tests_on_reif, []).
+
+:- use_module(library(reif)).
+
+test_queries_on_arithmetic_comparison :-
+    <(1, 2, T0), T0 == true,
+    <(2, 1, T1), T1 == false,
+    =:=(1, 1.0, T2), T2 == true,
+    =\=(1, 1.0, T3), T3 == false,
+    >=(2.5, 2, T4), T4 == true,
+    =<(3 + 1, 2 * 2, T5), T5 == true,
+    >(0.1, 1, T6), T6 == false,
+    if_(<(1, 2), X = less, X = not_less),
+    X == less,
+    tpartition(<(2), [1, 2, 3, 4.0], Gs, Ls),
+    Gs == [3, 4.0],
+    Ls == [1, 2],
+    catch(<(_, 1, _), error(instantiation_error, _), true),
+    expand_goal(<(A, B, T7), tests_on_reif, G0),
+    G0 == (A < B -> T7 = true ; T7 = false),
+    expand_goal(if_(A =< B, Y = le, Y = gt), tests_on_reif, G1),
+    G1 == (A =< B -> Y = le ; Y = gt),
+    findall(R, cut_in_branch(0, R), Rs),
+    Rs == [a, c].
+
+cut_in_branch(X, R) :-
+    if_(X < 1, ( !, R = a ), R = b).
+cut_in_branch(_, c).
+
+:- initialization(test_queries_on_arithmetic_comparison).
@@ -0,0 +1,21 @@
+:- module(tests_on_reif_goal_expansion, []).
+
+:- use_module(library(reif), [if_/3]).
+
+% reif's goal expansion is installed in user, but this module defines
+% its own </3, whose calls must be left as they are.
+
+<(X, Y, lt(X, Y)).
+
+local_less_than(T) :-
+    <(1, 2, T).
+
+test_queries_on_unrelated_comparison :-
+    local_less_than(T0),
+    T0 == lt(1, 2),
+    expand_goal(<(A, B, T1), tests_on_reif_goal_expansion, G0),
+    G0 == <(A, B, T1),
+    expand_goal(reif:<(A, B, T2), tests_on_reif_goal_expansion, G1),
+    G1 == (A < B -> T2 = true ; T2 = false).
+
+:- initialization(test_queries_on_unrelated_comparison).
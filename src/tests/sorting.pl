@@ -0,0 +1,78 @@
+:- module(tests_on_sorting, []).
+
+:- use_module(library(lists)).
+
+test_queries_on_msort :-
+    msort([3,1,2,1], S0),
+    S0 == [1,1,2,3],
+    msort([], S1),
+    S1 == [],
+    msort([b, 2, f(x), 1.0, a, 2, "s", X, b], S2),
+    S2 == [X, 1.0, 2, 2, a, b, b, f(x), "s"],
+    msort([f(Y), g(Y), f(Y)], S3),
+    S3 == [f(Y), f(Y), g(Y)],
+    catch(msort(_, _), error(instantiation_error, _), true),
+    catch(msort([a|b], _), error(type_error(list, [a|b]), _), true).
+
+test_queries_on_keysort :-
+    keysort([b-1, a-2, b-0, a-1], S0),
+    S0 == [a-2, a-1, b-1, b-0],
+    keysort([k-X, k-Y, j-Z], S1),
+    S1 == [j-Z, k-X, k-Y],
+    P =.. [-, c, 3],
+    keysort([d-4, P], S2),
+    S2 == [c-3, d-4],
+    catch(keysort([b-1, a], _), error(type_error(pair, a), _), true),
+    catch(keysort([f(a)], _), error(type_error(pair, f(a)), _), true),
+    catch(keysort(_, _), error(instantiation_error, _), true),
+    catch(keysort([_], _), error(instantiation_error, _), true).
+
+by_length(Order, A, B) :-
+    length(A, LA),
+    length(B, LB),
+    compare(Order, LA, LB).
+
+by_key(Order, K1-_, K2-_) :-
+    compare(Order, K1, K2).
+
+descending(Order, A, B) :-
+    compare(Order, B, A).
+
+any_order(<, _, _).
+any_order(>, _, _).
+
+test_queries_on_predsort :-
+    predsort(by_length, [[a,b,c], [d], [e,f], [g]], S0),
+    S0 == [[d], [e,f], [a,b,c]],
+    predsort(by_key, [b-1, a-2, b-3, c-4], S1),
+    S1 == [a-2, b-1, c-4],
+    predsort(by_key, [], S2),
+    S2 == [],
+    findall(S3, predsort(any_order, [1,2,3], S3), Ss),
+    Ss = [_],
+    predsort(descending, [1,3,2,3], S4),
+    S4 == [3,2,1].
+
+test_queries_on_sort4 :-
+    sort(0, @>=, [1, 3, 2, 3], S0),
+    S0 == [3, 3, 2, 1],
+    sort(0, @>, [1, 3, 2, 3], S1),
+    S1 == [3, 2, 1],
+    sort(0, @=<, [c, a, b, a], S2),
+    S2 == [a, a, b, c],
+    sort(1, @<, [f(2, a), f(1, b), f(2, c)], S3),
+    S3 == [f(1, b), f(2, a)],
+    sort(2, @>=, [p(x, 1), p(y, 2), p(z, 1)], S4),
+    S4 == [p(y, 2), p(x, 1), p(z, 1)],
+    sort(1, @=<, [b-1, a-2, b-0], S5),
+    S5 == [a-2, b-1, b-0],
+    sort(0, @<, [], S6),
+    S6 == [],
+    catch(sort(0, foo, [], _), error(domain_error(order, foo), _), true),
+    catch(sort(-1, @<, [], _), error(domain_error(not_less_than_zero, -1), _), true),
+    catch(sort(1, @<, [a], _), error(type_error(compound, a), _), true).
+
+:- initialization(test_queries_on_msort).
+:- initialization(test_queries_on_keysort).
+:- initialization(test_queries_on_predsort).
+:- initialization(test_queries_on_sort4).
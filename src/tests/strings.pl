@@ -0,0 +1,222 @@
+:- module(tests_on_strings, []).
+
+:- use_module(library(charsio)).
+:- use_module(library(lists)).
+
+test_queries_on_split_string :-
+    split_string("a, b ,c", ",", " ", L0),
+    L0 == ["a", "b", "c"],
+    split_string("/home//jan///nice/path", "/", "", L1),
+    L1 == ["", "home", "", "jan", "", "", "nice", "path"],
+    split_string("SWI-Prolog, 7.0", ",", " ", L2),
+    L2 == ["SWI-Prolog", "7.0"],
+    split_string("  a word ", "", " ", L3),
+    L3 == ["a word"],
+    split_string("", "", "", L4),
+    L4 == [[]],
+    split_string("a, ", ",", " ", L8),
+    L8 == ["a", []],
+    split_string("//a//b//", "/", "/", L9),
+    L9 == ["a", "b"],
+    split_string("a.b;c", ".;", "", L5),
+    L5 == ["a", "b", "c"],
+    split_string(",", ",", "", L6),
+    L6 == [[], []],
+    split_string("/home//jan///nice/path", "/", "/", L7),
+    L7 == ["home", "jan", "nice", "path"],
+    catch(split_string(_, ",", "", _), error(instantiation_error, _), true).
+
+:- initialization(test_queries_on_split_string).
+
+test_queries_on_string_code :-
+    string_code(1, "abc", C1),
+    C1 == 0'a,
+    string_code(3, "abc", C3),
+    C3 == 0'c,
+    \+ string_code(0, "abc", _),
+    \+ string_code(4, "abc", _),
+    \+ string_code(-1, "abc", _),
+    \+ string_code(1, [], _),
+    string_code(2, "héllo", C4),
+    C4 == 233,
+    string_code(5, "héllo", C5),
+    C5 == 0'o,
+    string_code(2, [a, b|"cd"], C6),
+    C6 == 0'b,
+    string_code(4, [a, b|"cd"], C7),
+    C7 == 0'd,
+    length(Long, 5000),
+    maplist(=(x), Long),
+    append(Long, "y", LongY),
+    string_code(5001, LongY, C8),
+    C8 == 0'y,
+    catch(string_code(_, "abc", _), error(instantiation_error, _), true),
+    catch(string_code(a, "abc", _), error(type_error(integer, a), _), true).
+
+:- initialization(test_queries_on_string_code).
+
+test_queries_on_case_folding :-
+    upcase_atom(hello, U0),
+    U0 == 'HELLO',
+    downcase_atom('HeLLo World', D0),
+    D0 == 'hello world',
+    upcase_atom('straße', U1),
+    U1 == 'STRASSE',
+    upcase_atom('ß', U2),
+    U2 == 'SS',
+    downcase_atom('ÀÉÎ', D1),
+    D1 == 'àéî',
+    upcase_atom('', U3),
+    U3 == '',
+    downcase_atom('', D2),
+    D2 == '',
+    upcase_atom(a, U4),
+    U4 == 'A',
+    upcase_atom([], U5),
+    U5 == [],
+    upcase_atom('abc123', 'ABC123'),
+    \+ downcase_atom('ABC', 'ABC'),
+    catch(upcase_atom(_, _), error(instantiation_error, _), true),
+    catch(downcase_atom(1, _), error(type_error(atom, 1), _), true).
+
+:- initialization(test_queries_on_case_folding).
+
+test_queries_on_sub_string :-
+    findall(B-A, sub_string("banana", B, 2, A, "an"), Ps0),
+    Ps0 == [1-3, 3-1],
+    sub_string("hello world", 6, 5, _, S0),
+    S0 == "world",
+    sub_string("hello world", _, 3, 0, S1),
+    S1 == "rld",
+    findall(S, sub_string("abc", _, _, _, S), Ss),
+    Ss == [[], "a", "ab", "abc", [], "b", "bc", [], "c", []],
+    sub_string([a, b|"cd"], 1, 2, A0, S2),
+    S2 == "bc",
+    A0 == 1,
+    \+ sub_string("abc", _, _, _, "d"),
+    \+ sub_string("abc", 2, 2, _, _),
+    catch(sub_string(_, _, _, _, _), error(instantiation_error, _), true),
+    catch(sub_string("abc", a, _, _, _), error(type_error(integer, a), _), true),
+    catch(sub_string("abc", -1, _, _, _), error(domain_error(not_less_than_zero, -1), _), true).
+
+:- initialization(test_queries_on_sub_string).
+
+test_queries_on_atomic_list_concat :-
+    atomic_list_concat([a, 'B', 1, 2.5, "", []], A0),
+    A0 == 'aB12.5[][]',
+    atomic_list_concat([], A1),
+    A1 == '',
+    atomic_list_concat([a, b, c], ', ', A2),
+    A2 == 'a, b, c',
+    atomic_list_concat([x], '-', A3),
+    A3 == x,
+    atomic_list_concat([1, 2], 0, A4),
+    A4 == '102',
+    atomic_list_concat(L0, ',', 'a,b,,c'),
+    L0 == [a, b, '', c],
+    atomic_list_concat(L1, ab, ab),
+    L1 == ['', ''],
+    atomic_list_concat(L2, '::', 'no separator'),
+    L2 == ['no separator'],
+    atomic_list_concat([a, X, c], '-', 'a-b-c'),
+    X == b,
+    \+ atomic_list_concat([a, b], '-', 'a+b'),
+    catch(atomic_list_concat([a|_], _), error(instantiation_error, _), true),
+    catch(atomic_list_concat([a, f(x)], _), error(type_error(atomic, f(x)), _), true),
+    catch(atomic_list_concat(_, '', abc), error(domain_error(non_empty_atom, ''), _), true),
+    catch(atomic_list_concat(_, ',', _), error(instantiation_error, _), true),
+    catch(atomic_list_concat([a], _, _), error(instantiation_error, _), true).
+
+:- initialization(test_queries_on_atomic_list_concat).
+
+test_queries_on_string_chars :-
+    string_chars("abc", Cs0),
+    Cs0 == [a, b, c],
+    string_chars(S0, [h, 'é', l]),
+    S0 == "hél",
+    string_chars("", Cs1),
+    Cs1 == [],
+    string_chars(S1, []),
+    S1 == "",
+    string_chars([x|"yz"], Cs2),
+    Cs2 == [x, y, z],
+    \+ string_chars("ab", [a, c]),
+    catch(string_chars(_, _), error(instantiation_error, _), true),
+    catch(string_chars(_, [a, _]), error(instantiation_error, _), true),
+    catch(string_chars(_, [a, 1]), error(type_error(character, 1), _), true),
+    catch(string_chars(abc, _), error(type_error(list, abc), _), true).
+
+:- initialization(test_queries_on_string_chars).
+
+test_queries_on_string_codes :-
+    string_codes("abc", Cs0),
+    Cs0 == [0'a, 0'b, 0'c],
+    string_codes(S0, [0'h, 233, 0'l]),
+    S0 == "hél",
+    string_codes("", Cs1),
+    Cs1 == [],
+    string_codes(S1, []),
+    S1 == "",
+    string_codes("ab", [0'a|Cs2]),
+    Cs2 == [0'b],
+    catch(string_codes(_, _), error(instantiation_error, _), true),
+    catch(string_codes(_, [0'a, _]), error(instantiation_error, _), true),
+    catch(string_codes(_, [a]), error(type_error(integer, a), _), true),
+    catch(string_codes(_, [-1]), error(representation_error(character_code), _), true).
+
+:- initialization(test_queries_on_string_codes).
+
+test_queries_on_atom_string :-
+    atom_string(abc, S0),
+    S0 == "abc",
+    atom_string(A0, "hél lo"),
+    A0 == 'hél lo',
+    atom_string(A0, S1),
+    atom_string(A1, S1),
+    A1 == A0,
+    atom_string(42, S2),
+    S2 == "42",
+    atom_string(-1.5, S3),
+    S3 == "-1.5",
+    atom_string(A2, "42"),
+    A2 == '42',
+    atom_string([], S4),
+    S4 == "[]",
+    atom_string(A3, ""),
+    A3 == '',
+    atom_string(abc, "abc"),
+    \+ atom_string(abc, "abd"),
+    catch(atom_string(_, _), error(instantiation_error, _), true),
+    catch(atom_string(f(x), _), error(type_error(atomic, f(x)), _), true),
+    string_to_atom("xyz", A4),
+    A4 == xyz,
+    string_to_atom(S5, xyz),
+    S5 == "xyz",
+    string_to_atom(S6, 7),
+    S6 == "7",
+    catch(string_to_atom(_, _), error(instantiation_error, string_to_atom/2), true).
+
+:- initialization(test_queries_on_atom_string).
+
+test_queries_on_text_concat :-
+    text_concat(abc, 42, T0),
+    T0 == abc42,
+    text_concat(1, 2, T1),
+    T1 == '12',
+    text_concat("ab", cd, T2),
+    T2 == "abcd",
+    text_concat(ab, "", T3),
+    T3 == "ab",
+    text_concat(abc, X0, abcdef),
+    X0 == def,
+    text_concat(X1, "ef", "abcdef"),
+    X1 == "abcd",
+    findall(X-Y, text_concat(X, Y, "abc"), Splits),
+    Splits == [[]-"abc", "a"-"bc", "ab"-"c", "abc"-[]],
+    findall(X-Y, text_concat(X, Y, ab), AtomSplits),
+    AtomSplits == [''-ab, a-b, ab-''],
+    \+ text_concat(x, _, abc),
+    catch(text_concat(_, _, _), error(instantiation_error, _), true),
+    catch(text_concat(f(x), a, _), error(type_error(list, f(x)), _), true).
+
+:- initialization(test_queries_on_text_concat).
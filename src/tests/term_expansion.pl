@@ -0,0 +1,25 @@
+:- module(tests_on_term_expansion, []).
+
+user:term_expansion((:- fact(X)), fact_from_directive(X)).
+user:term_expansion(unchanged(X), unchanged(X)).
+user:term_expansion(grow(X), grow(s(X))).
+
+user:goal_expansion(grow_goal(X), grow_goal(s(X))).
+
+:- fact(a).
+:- fact(b).
+
+unchanged(1).
+
+test_queries_on_term_expansion :-
+    findall(X, fact_from_directive(X), Xs),
+    Xs == [a,b],
+    unchanged(1),
+    catch(( expand_term(grow(0), _), false ),
+          error(resource_error(expansion_depth), term_expansion/2),
+          true),
+    catch(( expand_goal(grow_goal(0), user, _), false ),
+          error(resource_error(expansion_depth), goal_expansion/2),
+          true).
+
+:- initialization(test_queries_on_term_expansion).
@@ -0,0 +1,134 @@
+:- module(tests_on_terms, []).
+
+:- use_module(library(between)).
+:- use_module(library(charsio)).
+:- use_module(library(lists)).
+:- use_module(library(terms)).
+
+test_queries_on_ground :-
+    ground(f(a, b)),
+    ground([1, 2.0, "abc", g(h(i))]),
+    \+ ground(f(a, _)),
+    \+ ground([a|_]),
+    \+ ground(f(g(h(_)))),
+    T0 = f(T0, a),
+    ground(T0),
+    T1 = f(T1, _),
+    \+ ground(T1),
+    numlist(1, 500000, L0),
+    ground(L0),
+    append(L0, [_], L1),
+    \+ ground(L1).
+
+test_queries_on_term_variables :-
+    term_variables(f(Y, X, Y), V0),
+    V0 == [Y, X],
+    term_variables([A, g(B, A)|C], V1),
+    V1 == [A, B, C],
+    term_variables(f(a, "b", 1), V2),
+    V2 == [],
+    T = f(T, Z, W),
+    term_variables(T, V3),
+    V3 == [Z, W],
+    term_variables(h(D, E), [D, E]),
+    term_variables(h(E, D), V4),
+    V4 == [E, D].
+
+test_queries_on_numbervars :-
+    T0 = f(X, _, X),
+    numbervars(T0, 0, E0),
+    E0 == 2,
+    T0 == f('$VAR'(0), '$VAR'(1), '$VAR'(0)),
+    write_term_to_chars(T0, [numbervars(true)], Cs0),
+    Cs0 == "f(A,B,A)",
+    numbervars(g(_, a, _), 25, E1),
+    E1 == 27,
+    numbervars(h(a), 3, E2),
+    E2 == 3,
+    \+ numbervars(f(_), 0, 0),
+    catch(numbervars(f(_), a, _), error(type_error(integer, a), _), true),
+    catch(numbervars(f(_), _, _), error(instantiation_error, _), true).
+
+:- initialization(test_queries_on_ground).
+:- initialization(test_queries_on_term_variables).
+:- initialization(test_queries_on_numbervars).
+
+test_queries_on_compare :-
+    compare(O0, _, 1), O0 == (<),
+    compare(O1, 1, a), O1 == (<),
+    compare(O2, 2.5, 1), O2 == (>),
+    compare(O3, a, f(a)), O3 == (<),
+    compare(O4, zebra, apple), O4 == (>),
+    compare(O5, f(a, b), g(a)), O5 == (>),
+    compare(O6, f(a), g(a)), O6 == (<),
+    compare(O7, f(a, b), f(a, c)), O7 == (<),
+    compare(O8, f(X), f(X)), O8 == (=),
+    compare(<, f(1.0, a), f(1.0, b)),
+    sort([f(1.0, b), f(1.0, a)], [f(1.0, a), f(1.0, b)]),
+    compare(=, [1, 2], [1, 2]),
+    T0 = f(T0), T1 = f(T1),
+    compare(O9, T0, T1), O9 == (=),
+    catch(compare(foo, a, b), error(domain_error(order, foo), _), true),
+    catch(compare(1, a, b), error(type_error(atom, 1), _), true).
+
+:- initialization(test_queries_on_compare).
+
+test_queries_on_term_hash :-
+    term_hash(f(a, [1, 2.5], "xy"), H0),
+    integer(H0),
+    H0 >= 0,
+    term_hash(f(a, [1, 2.5], "xy"), H1),
+    H0 == H1,
+    X = g(b),
+    term_hash(f(X, X), H2),
+    term_hash(f(g(b), g(b)), H3),
+    H2 == H3,
+    term_hash("ab", H4),
+    term_hash([a, b], H5),
+    H4 == H5,
+    term_hash(f(a), H6),
+    term_hash(f(b), H7),
+    H6 \== H7,
+    term_hash(f(a, b), H8),
+    term_hash(f(b, a), H9),
+    H8 \== H9,
+    term_hash(1, H10),
+    term_hash(1.0, H11),
+    H10 \== H11,
+    term_hash(a, H12),
+    term_hash(a(b), H13),
+    H12 \== H13,
+    \+ term_hash(f(_), _),
+    term_hash(a, H12),
+    T = f(T),
+    catch(term_hash(T, _), error(type_error(acyclic_term, T), _), true).
+
+:- initialization(test_queries_on_term_hash).
+
+test_queries_on_identity :-
+    f(a, "bc", [1]) == f(a, [b, c], [1]),
+    X == X,
+    \+ X == _,
+    X \== _,
+    \+ f(X) == f(a),
+    var(X),
+    X = Y,
+    X == Y,
+    \+ a == b,
+    a \== b,
+    \+ 1 == 1.0,
+    1 \== 1.0,
+    1.0 == 1.0,
+    \+ f(1.0, a) == f(1.0, b),
+    f(1.0, a) \== f(1.0, b),
+    \+ 0 == -0.0,
+    \+ f(a, b) == f(a, b, c),
+    \+ f(a) == g(a),
+    A = g(A), B = g(B),
+    A == B,
+    C = g(a, C), D = g(b, D),
+    C \== D,
+    current_output(S),
+    S == S.
+
+:- initialization(test_queries_on_identity).
@@ -0,0 +1,48 @@
+:- module(tests_on_when, []).
+
+:- use_module(library(when)).
+
+test_queries_on_when :-
+    when(nonvar(X0), G0 = done),
+    var(G0),
+    X0 = f(_),
+    G0 == done,
+    when(nonvar(a), G1 = done),
+    G1 == done,
+    when((ground(X1), ground(Y1)), G2 = done),
+    X1 = f(A1),
+    var(G2),
+    A1 = 1,
+    var(G2),
+    Y1 = 2,
+    G2 == done,
+    when((nonvar(X2) ; nonvar(Y2)), G3 = done),
+    X2 = a,
+    G3 == done,
+    Y2 = b,
+    when(?=(X3, Y3), G4 = done),
+    X3 = a,
+    var(G4),
+    Y3 = b,
+    G4 == done,
+    when(?=(X4, Y4), G5 = done),
+    X4 = Y4,
+    G5 == done,
+    \+ ( when(nonvar(X5), fail), X5 = 1 ),
+    when(?=(X6, Y6), (Y6 \== a, G6 = done)),
+    X6 = a,
+    var(G6),
+    Y6 = b,
+    G6 == done,
+    when(?=(X7, Y7), true),
+    X7 = f(_),
+    Y7 = f(_),
+    copy_term(X7-Y7, _, Gs7),
+    Gs7 = [when:when(?=(_, _), true)],
+    when((nonvar(X8) ; nonvar(Y8)), true),
+    copy_term(X8-Y8, _, Gs8),
+    Gs8 = [when:when((nonvar(_) ; nonvar(_)), true)],
+    catch(when(_, true), error(instantiation_error, _), true),
+    catch(when(foo, true), error(domain_error(when_condition, foo), _), true).
+
+:- initialization(test_queries_on_when).
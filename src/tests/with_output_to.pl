@@ -0,0 +1,61 @@
+:- module(tests_on_with_output_to, []).
+
+:- use_module(library(iso_ext)).
+
+test_queries_on_with_output_to :-
+    with_output_to(string(S0), write(hello)),
+    S0 == "hello",
+    with_output_to(atom(A0), (write(a), nl, writeq('B'))),
+    A0 == 'a\n\'B\'',
+    with_output_to(codes(Cs), write(ab)),
+    Cs == [0'a, 0'b],
+    with_output_to(chars(Chs), true),
+    Chs == [],
+    \+ with_output_to(string(_), (write(x), fail)),
+    with_output_to(string(S1), ( write(outer),
+                                 with_output_to(atom(A1), write(inner)),
+                                 write(A1) )),
+    S1 == "outerinner",
+    current_output(Stream),
+    catch(with_output_to(string(_), (write(x), throw(ball))), ball, true),
+    current_output(Stream),
+    catch(with_output_to(_, true), error(instantiation_error, _), true),
+    catch(with_output_to(foo(_), true), error(domain_error(output_sink, foo(_)), _), true).
+
+:- initialization(test_queries_on_with_output_to).
+
+test_queries_on_tab :-
+    with_output_to(string(S0), tab(3)),
+    S0 == "   ",
+    with_output_to(string(S1), (write(a), tab(0), write(b))),
+    S1 == "ab",
+    with_output_to(string(S2), (current_output(S), tab(S, 2), write(x))),
+    S2 == "  x",
+    catch(tab(_), error(instantiation_error, _), true),
+    catch(tab(a), error(type_error(integer, a), _), true),
+    catch(tab(1.0), error(type_error(integer, 1.0), _), true),
+    catch(tab(-1), error(domain_error(not_less_than_zero, -1), _), true),
+    catch(( tab(100000000000000000000000000000), false ),
+          error(representation_error(max_integer), tab/2),
+          true),
+    catch(tab(foo, 1), error(existence_error(stream, foo), _), true).
+
+:- initialization(test_queries_on_tab).
+
+test_queries_on_writeln_and_print :-
+    with_output_to(string(S0), writeln('A b')),
+    S0 == "A b\n",
+    with_output_to(string(S1), writeln(f(x, "y"))),
+    S1 == "f(x,[y])\n",
+    with_output_to(string(S2), print('A b')),
+    S2 == "'A b'",
+    with_output_to(string(S3), print(f('$VAR'(1), [a]))),
+    S3 == "f(B,[a])",
+    assertz(user:(portray(secret(_)) :- write('<hidden>'))),
+    with_output_to(string(S4), print(secret(42))),
+    S4 == "<hidden>",
+    with_output_to(string(S5), print(public(42))),
+    S5 == "public(42)",
+    retractall(user:portray(_)).
+
+:- initialization(test_queries_on_writeln_and_print).
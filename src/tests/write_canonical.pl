@@ -0,0 +1,20 @@
+:- module(tests_on_write_canonical, []).
+
+:- use_module(library(charsio)).
+:- use_module(library(iso_ext)).
+:- use_module(library(lists)).
+
+round_trip(T0) :-
+    with_output_to(chars(Cs0), write_canonical(T0)),
+    append(Cs0, " .", Cs),
+    read_term_from_chars(Cs, T),
+    T =@= T0.
+
+test_queries_on_write_canonical :-
+    maplist(round_trip, [1+2*3, -(1), -(-(1)), - a, 'hello world', [],
+                         [a,b|c], "abc", {a,b}, (a :- b, c ; d),
+                         f(A, _, A), '$VAR'(1), 'ñ', -3, 1.5, (',')]),
+    with_output_to(chars(Cs0), write_canonical(1+'$VAR'(1))),
+    Cs0 == "+(1,'$VAR'(1))".
+
+:- initialization(test_queries_on_write_canonical).
@@ -0,0 +1,32 @@
+:- module(tests_on_yall, []).
+
+:- use_module(library(arithmetic)).
+:- use_module(library(lists)).
+:- use_module(library(yall)).
+
+test_queries_on_yall :-
+    maplist([X]>>(X > 0), [1, 2, 3]),
+    \+ maplist([X]>>(X > 1), [1, 2, 3]),
+    maplist([X, Y]>>(Y is X * 2), [1, 2, 3], L0),
+    L0 == [2, 4, 6],
+    foldl([E, A0, A]>>(A is A0 + E), [1, 2, 3], 0, S),
+    S == 6,
+    N = 3,
+    maplist([X, Y]>>(Y is X * N), [1, 2], L1),
+    L1 == [3, 6],
+    maplist([X]>>succ(X), [1, 2], L2),
+    L2 == [2, 3],
+    call([X, Y]>>(X = Y), a, B0),
+    B0 == a,
+    call([]>>true),
+    maplist(F/[X]>>(X = F), [A1, A2]),
+    A1 == F,
+    A2 == F,
+    maplist([X]>>(Z = X), [1, 2]),
+    var(Z),
+    G/[X, Y]>>(Y = X-G) = Lambda,
+    G = g,
+    call(Lambda, a, P),
+    P == a-g.
+
+:- initialization(test_queries_on_yall).
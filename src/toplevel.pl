@@ -177,6 +177,36 @@ submit_query_and_print_results(Term0, VarList) :-
                        bb_put('$first_answer', false)).
 
 
+% Called from Machine::run_query_collecting. Collects at most Max
+% instantiations of VarList (Max is an integer or the atom inf) and
+% always succeeds deterministically, so the machine can be reused.
+'$collect_answers'(Term0, VarList, Max, Answers, Outcome) :-
+    catch(collect_answers(Term0, VarList, Max, Answers, Outcome),
+          E,
+          ( Answers = [], Outcome = exception(E) )),
+    !.
+
+collect_answers(Term0, VarList, Max, Answers, Outcome) :-
+    expand_goal(call(Term0), user, call(Term)),
+    (  Max == inf ->
+       findall(VarList, '$call'(Term), Answers),
+       Outcome = complete
+    ;  Limit is Max + 1,
+       findall(VarList,
+               ( call_nth('$call'(Term), N),
+                 ( N =:= Limit -> ! ; true )
+               ),
+               Answers0),
+       % the answers are truncated only if there is one beyond Max.
+       (  length(Answers0, Limit) ->
+          append(Answers, [_], Answers0),
+          Outcome = truncated
+       ;  Answers = Answers0,
+          Outcome = complete
+       )
+    ).
+
+
 needs_bracketing(Value, Op) :-
     catch((functor(Value, F, _),
 	       current_op(EqPrec, EqSpec, Op),
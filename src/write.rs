@@ -35,6 +35,8 @@ impl fmt::Display for REPLCodePtr {
                 write!(f, "REPLCodePtr::AddInSituFilenameModule"),
             REPLCodePtr::AbolishClause =>
                 write!(f, "REPLCodePtr::AbolishClause"),
+            REPLCodePtr::DeclareDynamicPredicate =>
+                write!(f, "REPLCodePtr::DeclareDynamicPredicate"),
             REPLCodePtr::Assertz =>
                 write!(f, "REPLCodePtr::Assertz"),
             REPLCodePtr::Asserta =>
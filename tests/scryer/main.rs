@@ -1,4 +1,5 @@
 mod helper;
 
 mod issues;
+mod query_api;
 mod src_tests;
@@ -0,0 +1,125 @@
+use scryer_prolog::machine::{Machine, QueryResult, Stream};
+
+fn new_machine() -> Machine {
+    Machine::new(
+        Stream::from(""),
+        Stream::from(String::new()),
+        Stream::stderr(),
+    )
+}
+
+fn answer(bindings: &[(&str, &str)]) -> Vec<(String, String)> {
+    bindings
+        .iter()
+        .map(|(name, value)| (name.to_string(), value.to_string()))
+        .collect()
+}
+
+#[test]
+fn repeat_limited_to_three_solutions() {
+    let mut wam = new_machine();
+
+    assert_eq!(
+        wam.run_query_collecting("repeat.", Some(3)),
+        Ok(QueryResult {
+            answers: vec![vec![], vec![], vec![]],
+            truncated: true,
+        })
+    );
+
+    assert_eq!(
+        wam.run_query_collecting("repeat, X = a.", Some(3)),
+        Ok(QueryResult {
+            answers: vec![answer(&[("X", "a")]); 3],
+            truncated: true,
+        })
+    );
+
+    // the machine is still usable after stopping early.
+    assert_eq!(
+        wam.run_query_collecting("use_module(library(lists)).", None),
+        Ok(QueryResult {
+            answers: vec![vec![]],
+            truncated: false,
+        })
+    );
+
+    assert_eq!(
+        wam.run_query_collecting("member(X, [a,'B',f(Y)])", Some(3)),
+        Ok(QueryResult {
+            answers: vec![
+                answer(&[("X", "a"), ("Y", "Y")]),
+                answer(&[("X", "'B'"), ("Y", "Y")]),
+                answer(&[("X", "f(Y)"), ("Y", "Y")]),
+            ],
+            truncated: false,
+        })
+    );
+}
+
+#[test]
+fn collect_all_solutions() {
+    let mut wam = new_machine();
+
+    wam.run_query_collecting("use_module(library(lists)).", None)
+        .unwrap();
+
+    assert_eq!(
+        wam.run_query_collecting("member(X, [1,2]).", None),
+        Ok(QueryResult {
+            answers: vec![answer(&[("X", "1")]), answer(&[("X", "2")])],
+            truncated: false,
+        })
+    );
+
+    assert_eq!(
+        wam.run_query_collecting("member(X, [1,2]).", Some(5)),
+        Ok(QueryResult {
+            answers: vec![answer(&[("X", "1")]), answer(&[("X", "2")])],
+            truncated: false,
+        })
+    );
+
+    // reaching the limit is not truncation unless a further solution
+    // exists.
+    assert_eq!(
+        wam.run_query_collecting("member(X, [1,2]).", Some(2)),
+        Ok(QueryResult {
+            answers: vec![answer(&[("X", "1")]), answer(&[("X", "2")])],
+            truncated: false,
+        })
+    );
+
+    assert_eq!(
+        wam.run_query_collecting("member(X, [1,2]).", Some(1)),
+        Ok(QueryResult {
+            answers: vec![answer(&[("X", "1")])],
+            truncated: true,
+        })
+    );
+
+    assert_eq!(
+        wam.run_query_collecting("false.", None),
+        Ok(QueryResult::default())
+    );
+}
+
+#[test]
+fn query_errors() {
+    let mut wam = new_machine();
+
+    assert_eq!(
+        wam.run_query_collecting("repeat, throw(ball).", Some(3)),
+        Err("ball".to_string())
+    );
+
+    assert!(wam.run_query_collecting("X = .", None).is_err());
+
+    assert_eq!(
+        wam.run_query_collecting("X = 1 + 2", Some(1)),
+        Ok(QueryResult {
+            answers: vec![answer(&[("X", "1+2")])],
+            truncated: false,
+        })
+    );
+}
@@ -1,5 +1,20 @@
 use crate::helper::{load_module_test, run_top_level_test_with_args};
 
+#[test]
+fn aggregate() {
+    load_module_test("src/tests/aggregate.pl", "");
+}
+
+#[test]
+fn assoc() {
+    load_module_test("src/tests/assoc.pl", "");
+}
+
+#[test]
+fn between() {
+    load_module_test("src/tests/between.pl", "");
+}
+
 #[test]
 fn builtins() {
     load_module_test("src/tests/builtins.pl", "");
@@ -10,21 +25,111 @@ fn call_with_inference_limit() {
     load_module_test("src/tests/call_with_inference_limit.pl", "");
 }
 
+#[test]
+fn char_io() {
+    load_module_test("src/tests/char_io.pl", "");
+}
+
+#[test]
+fn clpz_domains() {
+    load_module_test("src/tests/clpz_domains.pl", "");
+}
+
+#[test]
+fn control() {
+    load_module_test("src/tests/control.pl", "");
+}
+
+#[test]
+fn copy_term() {
+    load_module_test("src/tests/copy_term.pl", "");
+}
+
+#[test]
+fn current_predicate() {
+    load_module_test("src/tests/current_predicate.pl", "");
+}
+
+#[test]
+fn dcgs() {
+    load_module_test("src/tests/dcgs.pl", "");
+}
+
+#[test]
+fn dif() {
+    load_module_test("src/tests/dif.pl", "");
+}
+
+#[test]
+fn dynamic() {
+    load_module_test("src/tests/dynamic.pl", "");
+}
+
+#[test]
+fn error() {
+    load_module_test("src/tests/error.pl", "");
+}
+
 #[test]
 fn facts() {
     load_module_test("src/tests/facts.pl", "");
 }
 
+#[test]
+fn findall() {
+    load_module_test("src/tests/findall.pl", "");
+}
+
+#[test]
+fn format() {
+    load_module_test("src/tests/format.pl", "");
+}
+
+#[test]
+fn freeze() {
+    load_module_test("src/tests/freeze.pl", "");
+}
+
+#[test]
+fn global_variables() {
+    load_module_test("src/tests/global_variables.pl", "");
+}
+
 #[test]
 fn hello_world() {
     load_module_test("src/tests/hello_world.pl", "Hello World!\n");
 }
 
+#[test]
+fn lists() {
+    load_module_test("src/tests/lists.pl", "");
+}
+
+#[test]
+fn numbers() {
+    load_module_test("src/tests/numbers.pl", "");
+}
+
+#[test]
+fn op_directive() {
+    load_module_test("src/tests/op_directive.pl", "");
+}
+
+#[test]
+fn sorting() {
+    load_module_test("src/tests/sorting.pl", "");
+}
+
+#[test]
+fn strings() {
+    load_module_test("src/tests/strings.pl", "");
+}
+
 #[test]
 fn syntax_error() {
     load_module_test(
         "tests-pl/syntax_error.pl",
-        "caught: error(syntax_error(incomplete_reduction),read_term/3:6)\n",
+        "caught: error(syntax_error(incomplete_reduction),read_term/3:position(6,0))\n",
     );
 }
 
@@ -33,6 +138,36 @@ fn predicates() {
     load_module_test("src/tests/predicates.pl", "");
 }
 
+#[test]
+fn prolog_flags() {
+    load_module_test("src/tests/prolog_flags.pl", "");
+}
+
+#[test]
+fn read_number() {
+    load_module_test("src/tests/read_number.pl", "");
+}
+
+#[test]
+fn read_term() {
+    load_module_test("src/tests/read_term.pl", "");
+}
+
+#[test]
+fn read_string() {
+    load_module_test("src/tests/read_string.pl", "");
+}
+
+#[test]
+fn reif() {
+    load_module_test("src/tests/reif.pl", "");
+}
+
+#[test]
+fn reif_goal_expansion() {
+    load_module_test("src/tests/reif_goal_expansion.pl", "");
+}
+
 #[test]
 fn rules() {
     load_module_test("src/tests/rules.pl", "");
@@ -52,6 +187,36 @@ fn setup_call_cleanup_process() {
     );
 }
 
+#[test]
+fn term_expansion() {
+    load_module_test("src/tests/term_expansion.pl", "");
+}
+
+#[test]
+fn terms() {
+    load_module_test("src/tests/terms.pl", "");
+}
+
+#[test]
+fn when() {
+    load_module_test("src/tests/when.pl", "");
+}
+
+#[test]
+fn with_output_to() {
+    load_module_test("src/tests/with_output_to.pl", "");
+}
+
+#[test]
+fn write_canonical() {
+    load_module_test("src/tests/write_canonical.pl", "");
+}
+
+#[test]
+fn yall() {
+    load_module_test("src/tests/yall.pl", "");
+}
+
 #[test]
 #[ignore] // ignored as this does not terminate
 fn clpz_load() {